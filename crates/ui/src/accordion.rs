@@ -214,6 +214,7 @@ impl RenderOnce for AccordionItem {
         let text_size = match self.size {
             Size::XSmall => rems(0.875),
             Size::Small => rems(0.875),
+            Size::XLarge => rems(1.125),
             _ => rems(1.0),
         };
 
@@ -237,6 +238,7 @@ impl RenderOnce for AccordionItem {
                             Size::XSmall => this.py_0().px_1p5(),
                             Size::Small => this.py_0p5().px_2(),
                             Size::Large => this.py_1p5().px_4(),
+                            Size::XLarge => this.py_2().px_5(),
                             _ => this.py_1().px_3(),
                         })
                         .when(self.open, |this| {
@@ -293,6 +295,7 @@ impl RenderOnce for AccordionItem {
                                 Size::XSmall => this.p_1p5(),
                                 Size::Small => this.p_2(),
                                 Size::Large => this.p_4(),
+                                Size::XLarge => this.p_5(),
                                 _ => this.p_3(),
                             })
                             .child(self.content),
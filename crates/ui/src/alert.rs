@@ -131,6 +131,14 @@ impl RenderOnce for Alert {
                 1.4,
                 px(0.),
             ),
+            Size::XLarge => (
+                cx.theme().radius * 3.,
+                px(24.),
+                px(20.),
+                px(16.),
+                1.4,
+                px(0.),
+            ),
             _ => (
                 cx.theme().radius * 2.,
                 px(16.),
@@ -158,6 +166,7 @@ impl RenderOnce for Alert {
                 .items_start()
                 .map(|this| match self.size {
                     Size::Large => this.text_base(),
+                    Size::XLarge => this.text_lg(),
                     _ => this.text_sm(),
                 })
                 .line_height(relative(line_height))
@@ -1,9 +1,9 @@
 use gpui::{
     div, prelude::FluentBuilder, px, relative, AnyElement, App, Div, IntoElement, ParentElement,
-    RenderOnce, Styled, Window,
+    Pixels, RenderOnce, Styled, Window,
 };
 
-use crate::{h_flex, red_500, white};
+use crate::{h_flex, ActiveTheme as _, Sizable, Size};
 
 #[derive(Default)]
 enum BadgeStyle {
@@ -12,12 +12,18 @@ enum BadgeStyle {
     Number,
 }
 
+/// A badge to show the unread count or a status dot at the top right of the children.
+///
+/// ```ignore
+/// Badge::new().count(3).child(Link::new("inbox").child("Inbox"))
+/// ```
 #[derive(IntoElement)]
 pub struct Badge {
     base: Div,
     count: usize,
     max: usize,
     style: BadgeStyle,
+    size: Size,
 }
 
 impl Badge {
@@ -27,6 +33,17 @@ impl Badge {
             count: 0,
             max: 99,
             style: Default::default(),
+            size: Size::default(),
+        }
+    }
+
+    /// Returns the dot size and the text size of the count.
+    fn metrics(&self) -> (Pixels, Pixels) {
+        match self.size {
+            Size::XSmall | Size::Small => (px(5.), px(9.)),
+            Size::Large => (px(8.), px(12.)),
+            Size::XLarge => (px(10.), px(14.)),
+            _ => (px(6.), px(10.)),
         }
     }
 
@@ -46,6 +63,13 @@ impl Badge {
     }
 }
 
+impl Sizable for Badge {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
 impl ParentElement for Badge {
     fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
         self.base.extend(elements);
@@ -53,16 +77,18 @@ impl ParentElement for Badge {
 }
 
 impl RenderOnce for Badge {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let (dot_size, text_size) = self.metrics();
+
         self.base.relative().when(self.count > 0, |this| {
             this.child(
                 h_flex()
                     .absolute()
                     .justify_center()
                     .rounded_full()
-                    .bg(red_500())
+                    .bg(cx.theme().danger)
                     .map(|this| match self.style {
-                        BadgeStyle::Dot => this.top(px(0.)).right(px(0.)).size(px(6.)),
+                        BadgeStyle::Dot => this.top(px(0.)).right(px(0.)).size(dot_size),
                         BadgeStyle::Number => {
                             let count = if self.count > self.max {
                                 format!("{}+", self.max)
@@ -75,8 +101,8 @@ impl RenderOnce for Badge {
                                 .py_0p5()
                                 .px_0p5()
                                 .min_w_3p5()
-                                .text_color(white())
-                                .text_size(px(10.))
+                                .text_color(cx.theme().danger_foreground)
+                                .text_size(text_size)
                                 .line_height(relative(1.))
                                 .child(count)
                         }
@@ -2,12 +2,13 @@ use std::rc::Rc;
 
 use crate::{
     h_flex, indicator::Indicator, tooltip::Tooltip, ActiveTheme, Colorize as _, Disableable, Icon,
-    Selectable, Sizable, Size, StyleSized,
+    Selectable, Sizable, Size, StyleSized, StyledExt as _,
 };
 use gpui::{
     div, prelude::FluentBuilder as _, relative, Action, AnyElement, App, ClickEvent, Corners, Div,
-    Edges, ElementId, Hsla, InteractiveElement, IntoElement, MouseButton, ParentElement, Pixels,
-    RenderOnce, SharedString, StatefulInteractiveElement as _, Styled, Window,
+    Edges, ElementId, FocusHandle, Hsla, InteractiveElement, IntoElement, KeyDownEvent,
+    MouseButton, ParentElement, Pixels, RenderOnce, SharedString, StatefulInteractiveElement as _,
+    Styled, Window,
 };
 
 #[derive(Clone, Copy)]
@@ -189,7 +190,8 @@ pub struct Button {
         SharedString,
         Option<(Rc<Box<dyn Action>>, Option<SharedString>)>,
     )>,
-    on_click: Option<Box<dyn Fn(&ClickEvent, &mut Window, &mut App) + 'static>>,
+    on_click: Option<Rc<dyn Fn(&ClickEvent, &mut Window, &mut App) + 'static>>,
+    focus_handle: Option<FocusHandle>,
     pub(crate) stop_propagation: bool,
     loading: bool,
     loading_icon: Option<Icon>,
@@ -217,6 +219,7 @@ impl Button {
             size: Size::Medium,
             tooltip: None,
             on_click: None,
+            focus_handle: None,
             stop_propagation: true,
             loading: false,
             compact: false,
@@ -300,7 +303,14 @@ impl Button {
         mut self,
         handler: impl Fn(&ClickEvent, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_click = Some(Box::new(handler));
+        self.on_click = Some(Rc::new(handler));
+        self
+    }
+
+    /// Set the focus handle of the Button, to focus it by the `tab` key of the parent
+    /// [`FocusableCycle`](crate::FocusableCycle), and click it by the `enter` or `space` key.
+    pub fn track_focus(mut self, focus_handle: &FocusHandle) -> Self {
+        self.focus_handle = Some(focus_handle.clone());
         self
     }
 
@@ -366,7 +376,7 @@ impl InteractiveElement for Button {
 }
 
 impl RenderOnce for Button {
-    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
         let style: ButtonVariant = self.variant;
         let normal_style = style.normal(self.outline, cx);
         let icon_size = match self.size {
@@ -393,14 +403,17 @@ impl RenderOnce for Button {
                         Size::XSmall => this.size_5(),
                         Size::Small => this.size_6(),
                         Size::Large | Size::Medium => this.size_8(),
+                        Size::XLarge => this.size_11(),
                     }
                 } else {
                     // Normal Button
+                    let compact = self.compact || cx.theme().density.is_compact();
                     match self.size {
                         Size::Size(size) => this.px(size * 0.2),
                         Size::XSmall => this.h_5().px_1(),
-                        Size::Small => this.h_6().px_3().when(self.compact, |this| this.px_1p5()),
-                        _ => this.h_8().px_4().when(self.compact, |this| this.px_2()),
+                        Size::Small => this.h_6().px_3().when(compact, |this| this.px_1p5()),
+                        Size::XLarge => this.h_11().px_6().when(compact, |this| this.px_3()),
+                        _ => this.h_8().px_4().when(compact, |this| this.px_2()),
                     }
                 }
             })
@@ -462,11 +475,29 @@ impl RenderOnce for Button {
                             cx.stop_propagation();
                         }
                     })
+                    .when_some(self.focus_handle.as_ref(), |this, _| {
+                        let on_click = on_click.clone();
+                        this.on_key_down(move |event: &KeyDownEvent, window, cx| {
+                            if matches!(event.keystroke.key.as_str(), "enter" | "space") {
+                                cx.stop_propagation();
+                                (on_click)(&ClickEvent::default(), window, cx);
+                            }
+                        })
+                    })
                     .on_click(move |event, window, cx| {
                         (on_click)(event, window, cx);
                     })
                 },
             )
+            .when_some(
+                self.focus_handle.as_ref().filter(|_| !self.disabled),
+                |this, focus_handle| {
+                    this.track_focus(focus_handle)
+                        .when(focus_handle.is_focused(window), |this| {
+                            this.focused_border(cx)
+                        })
+                },
+            )
             .when(self.disabled, |this| {
                 let disabled_style = style.disabled(self.outline, cx);
                 this.bg(disabled_style.bg)
@@ -483,6 +514,7 @@ impl RenderOnce for Button {
                     .map(|this| match self.size {
                         Size::XSmall => this.gap_1(),
                         Size::Small => this.gap_1(),
+                        Size::XLarge => this.gap_3(),
                         _ => this.gap_2(),
                     })
                     .when(!self.loading, |this| {
@@ -119,6 +119,7 @@ impl RenderOnce for Toggle {
                 Size::XSmall => this.min_w_5().h_5().px_0p5().text_xs(),
                 Size::Small => this.min_w_6().h_6().px_1().text_sm(),
                 Size::Large => this.min_w_9().h_9().px_3().text_lg(),
+                Size::XLarge => this.min_w_11().h_11().px_4().text_xl(),
                 _ => this.min_w_8().h_8().px_2(),
             })
             .rounded(cx.theme().radius)
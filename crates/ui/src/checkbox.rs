@@ -119,6 +119,7 @@ impl RenderOnce for Checkbox {
                     Size::Small => this.text_sm(),
                     Size::Medium => this.text_base(),
                     Size::Large => this.text_lg(),
+                    Size::XLarge => this.text_xl(),
                     _ => this,
                 })
                 .child(
@@ -129,6 +130,7 @@ impl RenderOnce for Checkbox {
                             Size::Small => this.size_3p5(),
                             Size::Medium => this.size_4(),
                             Size::Large => this.size(rems(1.125)),
+                            Size::XLarge => this.size_6(),
                             _ => this.size_4(),
                         })
                         .flex_shrink_0()
@@ -149,6 +151,7 @@ impl RenderOnce for Checkbox {
                                     Size::Small => this.size_2p5(),
                                     Size::Medium => this.size_3(),
                                     Size::Large => this.size_3p5(),
+                                    Size::XLarge => this.size_4(),
                                     _ => this.size_3(),
                                 })
                                 .text_color(icon_color)
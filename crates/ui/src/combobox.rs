@@ -0,0 +1,433 @@
+use gpui::{
+    anchored, canvas, deferred, div, prelude::FluentBuilder, px, rems, App, AppContext, Bounds,
+    ClickEvent, Context, ElementId, Entity, EventEmitter, FocusHandle, Focusable,
+    InteractiveElement, IntoElement, KeyBinding, Length, ParentElement, Pixels, Render,
+    SharedString, StatefulInteractiveElement, Styled, Subscription, Window,
+};
+
+use crate::{
+    actions::{Cancel, SelectNext, SelectPrev},
+    dropdown::DropdownItem,
+    h_flex,
+    input::{sized_clear_button, InputEvent, TextInput},
+    list::ListItem,
+    v_flex, ActiveTheme, Icon, IconName, Sizable, Size, StyleSized, StyledExt,
+};
+
+const CONTEXT: &str = "Combobox";
+pub fn init(cx: &mut App) {
+    cx.bind_keys([
+        KeyBinding::new("up", SelectPrev, Some(CONTEXT)),
+        KeyBinding::new("down", SelectNext, Some(CONTEXT)),
+        KeyBinding::new("escape", Cancel, Some(CONTEXT)),
+    ])
+}
+
+pub enum ComboboxEvent<T: DropdownItem + 'static> {
+    /// The selected value has changed, `None` means the value has been cleared.
+    Change(Option<T::Value>),
+}
+
+/// A Combobox element, a text input to filter the options and a popup list to select.
+///
+/// The items are filtered by the input text (case-insensitive) with their `title`.
+pub struct Combobox<T: DropdownItem + Clone + 'static> {
+    id: ElementId,
+    input: Entity<TextInput>,
+    items: Vec<T>,
+    matched_items: Vec<T>,
+    /// The highlighted index in the matched items, for keyboard navigation.
+    highlighted_index: Option<usize>,
+    selected_value: Option<T::Value>,
+    open: bool,
+    cleanable: bool,
+    placeholder: Option<SharedString>,
+    size: Size,
+    width: Length,
+    /// Store the bounds of the input
+    bounds: Bounds<Pixels>,
+    /// The text set by selecting an item, to skip filtering on its change event.
+    selected_text: Option<SharedString>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl<T> Combobox<T>
+where
+    T: DropdownItem + Clone + 'static,
+    T::Value: PartialEq,
+{
+    pub fn new(
+        id: impl Into<ElementId>,
+        items: impl Into<Vec<T>>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        let items = items.into();
+        let input = cx.new(|cx| {
+            TextInput::new(window, cx)
+                .appearance(false)
+                .blur_on_escape(false)
+        });
+
+        let _subscriptions = vec![cx.subscribe_in(&input, window, Self::on_input_event)];
+
+        Self {
+            id: id.into(),
+            input,
+            matched_items: items.clone(),
+            items,
+            highlighted_index: None,
+            selected_value: None,
+            open: false,
+            cleanable: false,
+            placeholder: None,
+            size: Size::default(),
+            width: Length::Auto,
+            bounds: Bounds::default(),
+            selected_text: None,
+            _subscriptions,
+        }
+    }
+
+    /// Set the width of the combobox input, default: Length::Auto
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Set the placeholder for display when the input is empty.
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Set true to show the clear button when the combobox has a value.
+    pub fn cleanable(mut self) -> Self {
+        self.cleanable = true;
+        self
+    }
+
+    /// Set the items for the combobox.
+    pub fn set_items(
+        &mut self,
+        items: impl Into<Vec<T>>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.items = items.into();
+        let query = self.input.read(cx).text().clone();
+        self.filter(&query, window, cx);
+    }
+
+    /// Returns the selected value.
+    pub fn selected_value(&self) -> Option<&T::Value> {
+        self.selected_value.as_ref()
+    }
+
+    /// Set the selected value of the combobox, this will not emit the change event.
+    pub fn set_selected_value(
+        &mut self,
+        value: Option<&T::Value>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let item = value.and_then(|value| self.items.iter().find(|item| item.value() == value));
+        self.select_item(item.cloned(), false, window, cx);
+    }
+
+    pub fn focus(&self, window: &mut Window, cx: &mut App) {
+        self.input.focus_handle(cx).focus(window);
+    }
+
+    fn select_item(
+        &mut self,
+        item: Option<T>,
+        emit: bool,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.selected_value = item.as_ref().map(|item| item.value().clone());
+        let text = item.map(|item| item.title()).unwrap_or_default();
+
+        self.selected_text = Some(text.clone());
+        self.input.update(cx, |input, cx| {
+            input.set_text(text, window, cx);
+        });
+
+        self.open = false;
+        self.matched_items = self.items.clone();
+        self.highlighted_index = None;
+        if emit {
+            cx.emit(ComboboxEvent::Change(self.selected_value.clone()));
+        }
+        cx.notify();
+    }
+
+    fn filter(&mut self, query: &str, _: &mut Window, cx: &mut Context<Self>) {
+        let query = query.to_lowercase();
+        self.matched_items = self
+            .items
+            .iter()
+            .filter(|item| item.title().to_lowercase().contains(&query))
+            .cloned()
+            .collect();
+        self.highlighted_index = if self.matched_items.is_empty() {
+            None
+        } else {
+            Some(0)
+        };
+        cx.notify();
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: &Entity<TextInput>,
+        event: &InputEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        match event {
+            InputEvent::Change(text) => {
+                if self.selected_text.take().as_ref() == Some(text) {
+                    return;
+                }
+
+                self.open = true;
+                self.filter(text, window, cx);
+            }
+            InputEvent::PressEnter { .. } => self.confirm(window, cx),
+            _ => {}
+        }
+    }
+
+    fn confirm(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if !self.open {
+            self.open = true;
+            cx.notify();
+            return;
+        }
+
+        if let Some(item) = self
+            .highlighted_index
+            .and_then(|ix| self.matched_items.get(ix))
+            .cloned()
+        {
+            self.select_item(Some(item), true, window, cx);
+        }
+    }
+
+    fn up(&mut self, _: &SelectPrev, _: &mut Window, cx: &mut Context<Self>) {
+        if !self.open || self.matched_items.is_empty() {
+            return;
+        }
+
+        let last_ix = self.matched_items.len() - 1;
+        self.highlighted_index = match self.highlighted_index {
+            Some(ix) if ix > 0 => Some(ix - 1),
+            _ => Some(last_ix),
+        };
+        cx.notify();
+    }
+
+    fn down(&mut self, _: &SelectNext, _: &mut Window, cx: &mut Context<Self>) {
+        if !self.open {
+            self.open = true;
+        }
+
+        if !self.matched_items.is_empty() {
+            let last_ix = self.matched_items.len() - 1;
+            self.highlighted_index = match self.highlighted_index {
+                Some(ix) if ix < last_ix => Some(ix + 1),
+                _ => Some(0),
+            };
+        }
+        cx.notify();
+    }
+
+    fn escape(&mut self, _: &Cancel, window: &mut Window, cx: &mut Context<Self>) {
+        if !self.open {
+            window.blur();
+            cx.propagate();
+            return;
+        }
+
+        // Restore the input text with the selected item.
+        let item = self
+            .selected_value
+            .as_ref()
+            .and_then(|value| self.items.iter().find(|item| item.value() == value))
+            .cloned();
+        self.select_item(item, false, window, cx);
+    }
+
+    fn toggle_menu(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        self.open = !self.open;
+        self.focus(window, cx);
+        cx.notify();
+    }
+
+    fn clean(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        cx.stop_propagation();
+        self.select_item(None, true, window, cx);
+    }
+
+    fn render_menu(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let popup_radius = cx.theme().radius.min(px(8.));
+
+        v_flex()
+            .id("combobox-menu")
+            .occlude()
+            .mt_1p5()
+            .p_1()
+            .max_h(rems(20.))
+            .overflow_y_scroll()
+            .bg(cx.theme().background)
+            .border_1()
+            .border_color(cx.theme().border)
+            .rounded(popup_radius)
+            .shadow_md()
+            .when(self.matched_items.is_empty(), |this| {
+                this.child(
+                    h_flex()
+                        .justify_center()
+                        .py_6()
+                        .text_color(cx.theme().muted_foreground.opacity(0.6))
+                        .child(Icon::new(IconName::Inbox).size(px(28.))),
+                )
+            })
+            .children(self.matched_items.iter().enumerate().map(|(ix, item)| {
+                let confirmed = self.selected_value.as_ref() == Some(item.value());
+
+                ListItem::new(("combobox-item", ix))
+                    .check_icon(IconName::Check)
+                    .selected(self.highlighted_index == Some(ix))
+                    .confirmed(confirmed)
+                    .input_text_size(self.size)
+                    .list_size(self.size)
+                    .child(div().whitespace_nowrap().child(item.title()))
+                    .on_click(cx.listener(move |this, _, window, cx| {
+                        if let Some(item) = this.matched_items.get(ix).cloned() {
+                            this.select_item(Some(item), true, window, cx);
+                        }
+                    }))
+            }))
+    }
+}
+
+impl<T> Sizable for Combobox<T>
+where
+    T: DropdownItem + Clone + 'static,
+{
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl<T> EventEmitter<ComboboxEvent<T>> for Combobox<T> where T: DropdownItem + Clone + 'static {}
+impl<T> Focusable for Combobox<T>
+where
+    T: DropdownItem + Clone + 'static,
+{
+    fn focus_handle(&self, cx: &App) -> FocusHandle {
+        self.input.focus_handle(cx)
+    }
+}
+
+impl<T> Render for Combobox<T>
+where
+    T: DropdownItem + Clone + 'static,
+    T::Value: PartialEq,
+{
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let is_focused = self.input.focus_handle(cx).is_focused(window);
+        let show_clean = self.cleanable && self.selected_value.is_some();
+        let view = cx.entity().clone();
+        let bounds = self.bounds;
+
+        let placeholder = self.placeholder.clone().unwrap_or_default();
+        self.input.update(cx, |input, cx| {
+            input.set_size(self.size, window, cx);
+            input.set_placeholder(placeholder, window, cx);
+        });
+
+        div()
+            .id(self.id.clone())
+            .key_context(CONTEXT)
+            .on_action(cx.listener(Self::up))
+            .on_action(cx.listener(Self::down))
+            .on_action(cx.listener(Self::escape))
+            .size_full()
+            .relative()
+            .input_text_size(self.size)
+            .child(
+                h_flex()
+                    .id(ElementId::Name(format!("{}-input", self.id).into()))
+                    .relative()
+                    .items_center()
+                    .justify_between()
+                    .gap_1()
+                    .bg(cx.theme().background)
+                    .border_1()
+                    .border_color(cx.theme().input)
+                    .rounded(cx.theme().radius)
+                    .when(cx.theme().shadow, |this| this.shadow_sm())
+                    .overflow_hidden()
+                    .map(|this| match self.width {
+                        Length::Definite(l) => this.flex_none().w(l),
+                        Length::Auto => this.w_full(),
+                    })
+                    .when(self.open || is_focused, |this| this.focused_border(cx))
+                    .input_pr_with_density(self.size, cx.theme().density)
+                    .child(div().flex_1().overflow_hidden().child(self.input.clone()))
+                    .when(show_clean, |this| {
+                        this.child(
+                            sized_clear_button(self.size, cx).on_click(cx.listener(Self::clean)),
+                        )
+                    })
+                    .when(!show_clean, |this| {
+                        let icon = if self.open {
+                            IconName::ChevronUp
+                        } else {
+                            IconName::ChevronDown
+                        };
+
+                        this.child(
+                            div()
+                                .id("toggle")
+                                .cursor_pointer()
+                                .child(
+                                    Icon::new(icon)
+                                        .xsmall()
+                                        .text_color(cx.theme().muted_foreground),
+                                )
+                                .on_click(cx.listener(Self::toggle_menu)),
+                        )
+                    })
+                    .child(
+                        canvas(
+                            move |bounds, _, cx| view.update(cx, |r, _| r.bounds = bounds),
+                            |_, _, _, _| {},
+                        )
+                        .absolute()
+                        .size_full(),
+                    ),
+            )
+            .when(self.open, |this| {
+                this.child(
+                    deferred(
+                        anchored().snap_to_window_with_margin(px(8.)).child(
+                            div()
+                                .occlude()
+                                .w(bounds.size.width)
+                                .child(self.render_menu(cx))
+                                .on_mouse_down_out(cx.listener(|this, _, window, cx| {
+                                    this.escape(&Cancel, window, cx);
+                                })),
+                        ),
+                    )
+                    .with_priority(1),
+                )
+            })
+    }
+}
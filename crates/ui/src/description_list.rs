@@ -240,6 +240,7 @@ impl RenderOnce for DescriptionList {
             Size::XSmall | Size::Small => px(2.),
             Size::Medium => px(4.),
             Size::Large => px(8.),
+            Size::XLarge => px(12.),
             _ => px(4.),
         };
 
@@ -248,6 +249,7 @@ impl RenderOnce for DescriptionList {
             Size::XSmall | Size::Small => (px(4.), px(2.)),
             Size::Medium => (px(8.), px(4.)),
             Size::Large => (px(12.), px(6.)),
+            Size::XLarge => (px(16.), px(8.)),
             _ => (px(8.), px(4.)),
         };
 
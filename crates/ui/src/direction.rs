@@ -0,0 +1,65 @@
+use gpui::{App, Global};
+
+/// The layout direction of the UI components.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Direction {
+    /// Left-to-right, default.
+    #[default]
+    Ltr,
+    /// Right-to-left, for the locales like Arabic, Hebrew.
+    Rtl,
+}
+
+impl Direction {
+    /// Returns the direction of the locale, e.g.: `ar`, `he-IL` are right-to-left.
+    pub fn from_locale(locale: &str) -> Self {
+        let lang = locale.split(['-', '_']).next().unwrap_or_default();
+        match lang.to_ascii_lowercase().as_str() {
+            "ar" | "ckb" | "dv" | "fa" | "he" | "iw" | "ps" | "sd" | "ug" | "ur" | "yi" => {
+                Self::Rtl
+            }
+            _ => Self::Ltr,
+        }
+    }
+
+    #[inline]
+    pub fn is_rtl(&self) -> bool {
+        matches!(self, Self::Rtl)
+    }
+}
+
+struct GlobalDirection(Option<Direction>);
+
+impl Global for GlobalDirection {}
+
+/// Returns the layout direction of the UI components.
+///
+/// This follows the current [`locale`](crate::locale), unless it is set by [`set_direction`].
+pub fn direction(cx: &App) -> Direction {
+    cx.try_global::<GlobalDirection>()
+        .and_then(|direction| direction.0)
+        .unwrap_or_else(|| Direction::from_locale(&crate::locale()))
+}
+
+/// Set the layout direction of the UI components, `None` to follow the current locale.
+///
+/// The opened windows are refreshed to apply the direction.
+pub fn set_direction(direction: Option<Direction>, cx: &mut App) {
+    cx.set_global(GlobalDirection(direction));
+    cx.refresh_windows();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Direction;
+
+    #[test]
+    fn test_direction_from_locale() {
+        assert_eq!(Direction::from_locale("en"), Direction::Ltr);
+        assert_eq!(Direction::from_locale("zh-CN"), Direction::Ltr);
+        assert_eq!(Direction::from_locale("ar"), Direction::Rtl);
+        assert_eq!(Direction::from_locale("he-IL"), Direction::Rtl);
+        assert_eq!(Direction::from_locale("fa_IR"), Direction::Rtl);
+        assert_eq!(Direction::from_locale(""), Direction::Ltr);
+    }
+}
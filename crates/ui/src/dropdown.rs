@@ -9,7 +9,7 @@ use rust_i18n::t;
 use crate::{
     actions::{Cancel, Confirm, SelectNext, SelectPrev},
     h_flex,
-    input::clear_button,
+    input::sized_clear_button,
     list::{List, ListDelegate, ListItem},
     v_flex, ActiveTheme, Disableable as _, Icon, IconName, Sizable, Size, StyleSized, StyledExt,
 };
@@ -473,6 +473,11 @@ where
         self.set_selected_index(selected_index, window, cx);
     }
 
+    /// Returns true if the dropdown menu is open.
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
     pub fn selected_index(&self, cx: &App) -> Option<usize> {
         self.list.read(cx).selected_index()
     }
@@ -687,7 +692,7 @@ where
                         Length::Auto => this.w_full(),
                     })
                     .when(outline_visible, |this| this.focused_border(cx))
-                    .input_size(self.size)
+                    .input_size_with_density(self.size, cx.theme().density)
                     .when(allow_open, |this| {
                         this.on_click(cx.listener(Self::toggle_menu))
                     })
@@ -706,7 +711,7 @@ where
                                     .child(self.display_title(window, cx)),
                             )
                             .when(show_clean, |this| {
-                                this.child(clear_button(cx).map(|this| {
+                                this.child(sized_clear_button(self.size, cx).map(|this| {
                                     if self.disabled {
                                         this.disabled(true)
                                     } else {
@@ -1,4 +1,55 @@
-use gpui::{App, FocusHandle, Window};
+use gpui::{App, FocusHandle, Focusable, Window};
+
+use crate::{input::SelectAll, Root};
+
+/// Focus the handle for the `autofocus` of the input components, called on the first render.
+///
+/// If multiple components set `autofocus`, the first in render order wins, the others are
+/// skipped while it keeps the focus.
+pub(crate) fn autofocus(handle: &FocusHandle, window: &mut Window, cx: &mut App) {
+    let claimed = window
+        .root::<Root>()
+        .flatten()
+        .and_then(|root| root.read(cx).autofocused.clone());
+    if claimed.map_or(false, |claimed| {
+        claimed != *handle && claimed.is_focused(window)
+    }) {
+        return;
+    }
+
+    handle.focus(window);
+    let handle = handle.clone();
+    Root::update(window, cx, move |root, _, _| {
+        root.autofocused = Some(handle)
+    });
+}
+
+/// Sort the focus handles by the tab index, like the `tabindex` in HTML.
+///
+/// The handles with a positive tab index come first in ascending order, followed by the ones
+/// with `0` or without tab index, and the ones with a negative tab index are skipped.
+/// The handles with the same (or without) tab index keep the given order.
+pub(crate) fn tab_order<T>(handles: impl IntoIterator<Item = (Option<isize>, T)>) -> Vec<T> {
+    let mut handles = handles
+        .into_iter()
+        .filter(|(tab_index, _)| tab_index.map_or(true, |tab_index| tab_index >= 0))
+        .collect::<Vec<_>>();
+    handles.sort_by_key(|(tab_index, _)| match tab_index {
+        Some(tab_index) if *tab_index > 0 => (0, *tab_index),
+        _ => (1, 0),
+    });
+    handles.into_iter().map(|(_, handle)| handle).collect()
+}
+
+/// A focusable component with a tab index, e.g. `TextInput`, `NumberInput`, `DatePicker`.
+///
+/// The tab index is set by the `tab_index` method of the component, and used by the
+/// [`FormField::track_focus_of`](crate::form::FormField::track_focus_of) to sort the fields,
+/// see [`tab_order`] for the order.
+pub trait TabStop: Focusable {
+    /// Returns the tab index of the component, `None` to follow the render order.
+    fn tab_stop_index(&self, cx: &App) -> Option<isize>;
+}
 
 /// A trait for views that can cycle focus between its children.
 ///
@@ -6,6 +57,24 @@ use gpui::{App, FocusHandle, Window};
 ///
 /// You should implement the `cycle_focus_handles` method to return a list of focus handles that
 /// should be cycled, and the cycle will follow the order of the list.
+///
+/// The input components (`TextInput`, `NumberInput`, `DatePicker`...) follow the same contract:
+///
+/// - Bind `tab` to `cycle_focus(true, ..)` and `shift-tab` to `cycle_focus(false, ..)`.
+/// - The text of the focused `TextInput` or `NumberInput` will be selected all.
+/// - The `escape` key blurs the focused component, if it is not handled by the component
+///   (e.g. close the popup, clear the selection).
+/// - The `DatePicker` traps the focus in its popup while it is open, the `tab` is propagated to
+///   the parent view after it is closed.
+///
+/// The [`Form`](crate::form::Form) cycles the fields by the [`FormField::tab_index`](crate::form::FormField::tab_index),
+/// then the render order.
+///
+/// ```ignore
+/// div()
+///     .on_action(cx.listener(|this, _: &Tab, window, cx| this.cycle_focus(true, window, cx)))
+///     .on_action(cx.listener(|this, _: &TabPrev, window, cx| this.cycle_focus(false, window, cx)))
+/// ```
 pub trait FocusableCycle {
     /// Returns a list of focus handles that should be cycled.
     fn cycle_focus_handles(&self, window: &mut Window, cx: &mut App) -> Vec<FocusHandle>
@@ -35,6 +104,28 @@ pub trait FocusableCycle {
             .unwrap_or(fallback_handle);
 
         target_focus_handle.focus(window);
+        // Select all text of the input, this is ignored by the non-input components.
+        window.dispatch_action(Box::new(SelectAll), cx);
         cx.stop_propagation();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::tab_order;
+
+    #[test]
+    fn test_tab_order() {
+        let order = tab_order([
+            (None, "a"),
+            (Some(2), "b"),
+            (Some(0), "c"),
+            (Some(-1), "d"),
+            (Some(1), "e"),
+            (Some(2), "f"),
+            (None, "g"),
+        ]);
+        assert_eq!(order, vec!["e", "b", "f", "a", "c", "g"]);
+        assert_eq!(tab_order::<&str>([]), Vec::<&str>::new());
+    }
+}
@@ -1,12 +1,17 @@
 use std::rc::{Rc, Weak};
 
 use gpui::{
-    div, prelude::FluentBuilder as _, px, AlignItems, AnyElement, AnyView, App, Axis, Div, Element,
-    ElementId, FocusHandle, InteractiveElement as _, IntoElement, ParentElement, Pixels, Rems,
-    RenderOnce, SharedString, Styled, Window,
+    div, prelude::FluentBuilder as _, px, AlignItems, AnyElement, AnyView, App, Axis, Context, Div,
+    Element, ElementId, Entity, FocusHandle, Focusable, InteractiveElement as _, IntoElement,
+    KeyDownEvent, MouseButton, ParentElement, Pixels, Rems, RenderOnce, SharedString,
+    StatefulInteractiveElement as _, Styled, Window,
 };
+use rust_i18n::t;
 
-use crate::{h_flex, v_flex, ActiveTheme as _, AxisExt, FocusableCycle, Sizable, Size, StyledExt};
+use crate::{
+    box_shadow, h_flex, input::TextInput, v_flex, ActiveTheme as _, AxisExt, FocusableCycle, Icon,
+    IconName, Sizable, Size, StyledExt, TabStop,
+};
 
 /// Create a new form with a vertical layout.
 pub fn v_form() -> Form {
@@ -118,10 +123,11 @@ impl FocusableCycle for Form {
     where
         Self: Sized,
     {
-        self.fields
-            .iter()
-            .filter_map(|item| item.focus_handle.clone())
-            .collect()
+        crate::focusable::tab_order(self.fields.iter().filter_map(|item| {
+            item.focus_handle
+                .clone()
+                .map(|handle| (item.tab_index, handle))
+        }))
     }
 }
 
@@ -178,7 +184,10 @@ pub struct FormField {
     label: Option<FieldBuilder>,
     no_label_indent: bool,
     focus_handle: Option<FocusHandle>,
+    tab_index: Option<isize>,
     description: Option<FieldBuilder>,
+    /// The error message, the field is displayed as invalid if it is set.
+    error: Option<FieldBuilder>,
     /// Used to render the actual form field, e.g.: TextInput, Switch...
     child: Div,
     visible: bool,
@@ -195,11 +204,13 @@ impl FormField {
             form: Weak::new(),
             label: None,
             description: None,
+            error: None,
             child: div(),
             visible: true,
             required: false,
             no_label_indent: false,
             focus_handle: None,
+            tab_index: None,
             align_items: None,
             props: FieldProps::default(),
         }
@@ -251,6 +262,24 @@ impl FormField {
         self
     }
 
+    /// Sets the error message for the form field, the field will be displayed as invalid.
+    pub fn error(mut self, error: impl Into<FieldBuilder>) -> Self {
+        self.error = Some(error.into());
+        self
+    }
+
+    /// Sets the error message for the form field using a function.
+    pub fn error_fn<F, E>(mut self, error: F) -> Self
+    where
+        E: IntoElement,
+        F: Fn(&mut Window, &mut App) -> E + 'static,
+    {
+        self.error = Some(FieldBuilder::Element(Rc::new(move |window, cx| {
+            error(window, cx).into_any_element()
+        })));
+        self
+    }
+
     /// Set the visibility of the form field, default is `true`.
     pub fn visible(mut self, visible: bool) -> Self {
         self.visible = visible;
@@ -271,6 +300,26 @@ impl FormField {
         self
     }
 
+    /// Set the focus handle and the tab index of the form field by the field component,
+    /// e.g. a `TextInput`, the tab index set by [`FormField::tab_index`] takes precedence.
+    pub fn track_focus_of<T: TabStop>(mut self, field: &Entity<T>, cx: &App) -> Self {
+        let field = field.read(cx);
+        self.focus_handle = Some(field.focus_handle(cx));
+        self.tab_index = self.tab_index.or(field.tab_stop_index(cx));
+        self
+    }
+
+    /// Set the tab index of the form field, to define the focus order independent of the layout.
+    ///
+    /// Like the `tabindex` in HTML, the fields with a positive tab index are focused first in
+    /// ascending order, then the ones with `0` or without tab index in the render order, and
+    /// the ones with a negative tab index are skipped by the `tab` key.
+    /// This requires the [`FormField::track_focus`] or [`FormField::track_focus_of`] to be set.
+    pub fn tab_index(mut self, tab_index: isize) -> Self {
+        self.tab_index = Some(tab_index);
+        self
+    }
+
     pub fn parent(mut self, form: &Rc<Form>) -> Self {
         self.form = Rc::downgrade(form);
         self
@@ -319,6 +368,7 @@ impl RenderOnce for FormField {
             self.props.label_width
         };
         let has_label = !self.no_label_indent;
+        let invalid = self.error.is_some();
 
         #[inline]
         fn wrap_div(layout: Axis) -> Div {
@@ -340,6 +390,7 @@ impl RenderOnce for FormField {
             Some(v) => v,
             None => match self.props.size {
                 Size::Large => px(8.),
+                Size::XLarge => px(12.),
                 Size::XSmall | Size::Small => px(4.),
                 _ => px(4.),
             },
@@ -390,7 +441,22 @@ impl RenderOnce for FormField {
                                 }),
                         )
                     })
-                    .child(div().w_full().child(self.child)),
+                    .child(
+                        div()
+                            .w_full()
+                            .rounded(cx.theme().radius)
+                            // Draw a ring around the control, to not override its own border.
+                            .when(invalid, |this| {
+                                this.shadow(smallvec::smallvec![box_shadow(
+                                    px(0.),
+                                    px(0.),
+                                    px(0.),
+                                    px(1.),
+                                    cx.theme().danger,
+                                )])
+                            })
+                            .child(self.child),
+                    ),
             )
             .child(
                 // Other
@@ -402,17 +468,257 @@ impl RenderOnce for FormField {
                             wrap_label(label_width),
                         )
                     })
-                    .when_some(self.description, |this, builder| {
-                        this.child(
-                            div()
-                                .text_xs()
-                                .text_color(cx.theme().muted_foreground)
-                                .child(builder.render(window, cx)),
-                        )
-                    }),
+                    .child(
+                        v_flex()
+                            .gap(inner_gap / 2.)
+                            .when_some(self.description, |this, builder| {
+                                this.child(
+                                    div()
+                                        .text_xs()
+                                        .text_color(cx.theme().muted_foreground)
+                                        .child(builder.render(window, cx)),
+                                )
+                            })
+                            .when_some(self.error, |this, builder| {
+                                this.child(
+                                    div()
+                                        .text_xs()
+                                        .text_color(cx.theme().danger)
+                                        .child(builder.render(window, cx)),
+                                )
+                            }),
+                    ),
+            )
+    }
+}
+
+/// A field component to be validated on submit by the [`FormValidator`].
+pub trait Validatable: Focusable {
+    /// Validate the current value, returns the error message if the value is invalid.
+    ///
+    /// The error is displayed by the [`FormErrors`], the field may also render its error state,
+    /// e.g. the [`TextInput`] updates its validity by the `rule`.
+    fn validate_field(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Result<(), SharedString>;
+}
+
+/// Returns `None` if the field is released.
+type ValidateFn =
+    Box<dyn Fn(&mut Window, &mut App) -> Option<(Result<(), SharedString>, FocusHandle)>>;
+
+/// Validate the registered fields of a form at once, e.g. on submit.
+///
+/// Keep it in the view state, the fields are validated in the registered order.
+///
+/// ```ignore
+/// let validator = FormValidator::new()
+///     .field("Email", &email_input)
+///     .field("Birthday", &birthday_picker);
+///
+/// // On submit, the first invalid field is focused.
+/// match self.validator.validate(window, cx) {
+///     Ok(()) => self.submit(cx),
+///     Err(errors) => self.errors = Some(errors),
+/// }
+/// ```
+#[derive(Default)]
+pub struct FormValidator {
+    fields: Vec<(SharedString, ValidateFn)>,
+}
+
+impl FormValidator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a field to validate with the label to display in the [`FormErrors`].
+    pub fn field<V: Validatable>(
+        mut self,
+        label: impl Into<SharedString>,
+        field: &Entity<V>,
+    ) -> Self {
+        // Not to keep the field alive by the validator in the view state.
+        let field = field.downgrade();
+        self.fields.push((
+            label.into(),
+            Box::new(move |window, cx| {
+                let field = field.upgrade()?;
+                let result = field.update(cx, |field, cx| field.validate_field(window, cx));
+                Some((result, field.focus_handle(cx)))
+            }),
+        ));
+        self
+    }
+
+    /// Validate all the fields, and focus the first invalid field.
+    ///
+    /// Returns the errors of the invalid fields to render, if any.
+    pub fn validate(&self, window: &mut Window, cx: &mut App) -> Result<(), FormErrors> {
+        let mut errors = FormErrors::new();
+        for (label, validate) in self.fields.iter() {
+            if let Some((Err(message), focus_handle)) = validate(window, cx) {
+                errors = errors.error(label.clone(), message, focus_handle, cx);
+            }
+        }
+
+        let Some(error) = errors.errors.first() else {
+            return Ok(());
+        };
+
+        error.field.focus(window);
+        Err(errors)
+    }
+}
+
+/// A summary of the errors in a form, each error focuses its field by click, or Enter and Space.
+///
+/// Render it at the top of the form after a failed submit, it renders nothing if there is no error.
+/// Keep it in the view state to keep the focus of the errors between renders, the errors can be
+/// cycled with [`FocusableCycle`].
+///
+/// ```ignore
+/// FormErrors::new()
+///     .input("Email", &self.email_input, cx)
+///     .error("Birthday", "Must be in the past", self.birthday.focus_handle(cx), cx)
+/// ```
+#[derive(IntoElement, Clone)]
+pub struct FormErrors {
+    title: Option<SharedString>,
+    errors: Vec<FormError>,
+}
+
+#[derive(Clone)]
+struct FormError {
+    label: SharedString,
+    message: SharedString,
+    /// The focus handle of the invalid field.
+    field: FocusHandle,
+    /// The focus handle of the error item.
+    focus_handle: FocusHandle,
+}
+
+impl FormErrors {
+    pub fn new() -> Self {
+        Self {
+            title: None,
+            errors: vec![],
+        }
+    }
+
+    /// Set the title of the summary, default is "Please fix the following errors:".
+    pub fn title(mut self, title: impl Into<SharedString>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Collect the error of the [`TextInput`], set by [`TextInput::set_validity`].
+    pub fn input(
+        mut self,
+        label: impl Into<SharedString>,
+        input: &Entity<TextInput>,
+        cx: &App,
+    ) -> Self {
+        if let Some(message) = input.read(cx).validation_error().cloned() {
+            self = self.error(label, message, input.focus_handle(cx), cx);
+        }
+        self
+    }
+
+    /// Add an error of the field to focus by the focus handle.
+    pub fn error(
+        mut self,
+        label: impl Into<SharedString>,
+        message: impl Into<SharedString>,
+        focus_handle: FocusHandle,
+        cx: &App,
+    ) -> Self {
+        self.errors.push(FormError {
+            label: label.into(),
+            message: message.into(),
+            field: focus_handle,
+            focus_handle: cx.focus_handle(),
+        });
+        self
+    }
+
+    /// Returns true if there is no error collected.
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+impl FocusableCycle for FormErrors {
+    fn cycle_focus_handles(&self, _: &mut Window, _: &mut App) -> Vec<FocusHandle>
+    where
+        Self: Sized,
+    {
+        self.errors
+            .iter()
+            .map(|error| error.focus_handle.clone())
+            .collect()
+    }
+}
+
+impl RenderOnce for FormErrors {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        if self.errors.is_empty() {
+            return div();
+        }
+
+        let title = self.title.unwrap_or_else(|| t!("Form.errors_title").into());
+
+        v_flex()
+            .w_full()
+            .gap_1()
+            .px_4()
+            .py_3()
+            .border_1()
+            .border_color(cx.theme().danger)
+            .rounded(cx.theme().radius)
+            .text_sm()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .font_medium()
+                    .text_color(cx.theme().danger)
+                    .child(Icon::new(IconName::CircleX).small())
+                    .child(title),
             )
+            .children(self.errors.into_iter().enumerate().map(|(ix, error)| {
+                let field = error.field.clone();
+                let focused = error.focus_handle.is_focused(window);
+
+                div()
+                    .id(("form-error", ix))
+                    .track_focus(&error.focus_handle)
+                    .pl_6()
+                    .border_1()
+                    .border_color(cx.theme().transparent)
+                    .rounded(cx.theme().radius)
+                    .when(focused, |this| this.focused_border(cx))
+                    .text_color(cx.theme().danger)
+                    .text_decoration_1()
+                    .text_decoration_color(cx.theme().danger)
+                    .cursor_pointer()
+                    .on_mouse_down(MouseButton::Left, |_, _, cx| cx.stop_propagation())
+                    .on_key_down({
+                        let field = field.clone();
+                        move |event: &KeyDownEvent, window, cx| {
+                            if matches!(event.keystroke.key.as_str(), "enter" | "space") {
+                                cx.stop_propagation();
+                                field.focus(window);
+                            }
+                        }
+                    })
+                    .on_click(move |_, window, _| field.focus(window))
+                    .child(format!("{}: {}", error.label, error.message))
+            }))
     }
 }
+
 impl RenderOnce for Form {
     fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
         let props = self.props;
@@ -420,6 +726,7 @@ impl RenderOnce for Form {
         let gap = match props.size {
             Size::XSmall | Size::Small => px(6.),
             Size::Large => px(12.),
+            Size::XLarge => px(16.),
             _ => px(8.),
         };
 
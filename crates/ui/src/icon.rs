@@ -23,10 +23,13 @@ pub enum IconName {
     ChevronLeft,
     ChevronRight,
     ChevronUp,
+    ChevronsLeft,
+    ChevronsRight,
     ChevronsUpDown,
     CircleCheck,
     CircleUser,
     CircleX,
+    Clock,
     Close,
     Copy,
     Dash,
@@ -101,10 +104,13 @@ impl IconName {
             Self::ChevronLeft => "icons/chevron-left.svg",
             Self::ChevronRight => "icons/chevron-right.svg",
             Self::ChevronUp => "icons/chevron-up.svg",
+            Self::ChevronsLeft => "icons/chevrons-left.svg",
+            Self::ChevronsRight => "icons/chevrons-right.svg",
             Self::ChevronsUpDown => "icons/chevrons-up-down.svg",
             Self::CircleCheck => "icons/circle-check.svg",
             Self::CircleUser => "icons/circle-user.svg",
             Self::CircleX => "icons/circle-x.svg",
+            Self::Clock => "icons/clock.svg",
             Self::Close => "icons/close.svg",
             Self::Copy => "icons/copy.svg",
             Self::Dash => "icons/dash.svg",
@@ -300,6 +306,7 @@ impl RenderOnce for Icon {
                 Size::Small => this.size_3p5(),
                 Size::Medium => this.size_4(),
                 Size::Large => this.size_6(),
+                Size::XLarge => this.size_8(),
             })
             .path(self.path)
     }
@@ -329,6 +336,7 @@ impl Render for Icon {
                 Size::Small => this.size_3p5(),
                 Size::Medium => this.size_4(),
                 Size::Large => this.size_6(),
+                Size::XLarge => this.size_8(),
             })
             .path(self.path.clone())
             .when_some(self.rotation, |this, rotation| {
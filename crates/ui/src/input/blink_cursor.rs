@@ -2,19 +2,22 @@ use std::time::Duration;
 
 use gpui::{Context, Timer};
 
-static INTERVAL: Duration = Duration::from_millis(500);
+pub(crate) static INTERVAL: Duration = Duration::from_millis(500);
 static PAUSE_DELAY: Duration = Duration::from_millis(300);
 
 /// To manage the Input cursor blinking.
 ///
-/// It will start blinking with a interval of 500ms.
+/// It will start blinking with a interval of 500ms by default.
 /// Every loop will notify the view to update the `visible`, and Input will observe this update to touch repaint.
 ///
+/// If the interval is `None`, the cursor will not blink and always keep visible.
+///
 /// The input painter will check if this in visible state, then it will draw the cursor.
 pub(crate) struct BlinkCursor {
     visible: bool,
     paused: bool,
     epoch: usize,
+    interval: Option<Duration>,
 }
 
 impl BlinkCursor {
@@ -23,11 +26,28 @@ impl BlinkCursor {
             visible: false,
             paused: false,
             epoch: 0,
+            interval: Some(INTERVAL),
+        }
+    }
+
+    /// Set the blink interval, `None` to disable blinking.
+    pub fn set_interval(&mut self, interval: Option<Duration>, cx: &mut Context<Self>) {
+        if self.interval == interval {
+            return;
         }
+
+        self.interval = interval;
+        // Reset the epoch to stop the running loop, it will be restart by next `start`.
+        self.epoch = 0;
+        cx.notify();
     }
 
     /// Start the blinking
     pub fn start(&mut self, cx: &mut Context<Self>) {
+        if self.interval.is_none() {
+            return;
+        }
+
         self.blink(self.epoch, cx);
     }
 
@@ -46,13 +66,17 @@ impl BlinkCursor {
             return;
         }
 
+        let Some(interval) = self.interval else {
+            return;
+        };
+
         self.visible = !self.visible;
         cx.notify();
 
         // Schedule the next blink
         let epoch = self.next_epoch();
         cx.spawn(async move |this, cx| {
-            Timer::after(INTERVAL).await;
+            Timer::after(interval).await;
             if let Some(this) = this.upgrade() {
                 this.update(cx, |this, cx| this.blink(epoch, cx)).ok();
             }
@@ -61,12 +85,16 @@ impl BlinkCursor {
     }
 
     pub fn visible(&self) -> bool {
-        // Keep showing the cursor if paused
-        self.paused || self.visible
+        // Keep showing the cursor if paused or not blinking.
+        self.paused || self.visible || self.interval.is_none()
     }
 
     /// Pause the blinking, and delay 500ms to resume the blinking.
     pub fn pause(&mut self, cx: &mut Context<Self>) {
+        if self.interval.is_none() {
+            return;
+        }
+
         self.paused = true;
         cx.notify();
 
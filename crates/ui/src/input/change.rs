@@ -1,7 +1,59 @@
 use std::{fmt::Debug, ops::Range};
 
+use gpui::SharedString;
+
 use crate::history::HistoryItem;
 
+/// An edit operation applied to the text of the input.
+///
+/// The ranges are the UTF-8 byte offsets in the text before the edit.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum EditOp {
+    /// Insert the text at the offset.
+    Insert { offset: usize, text: SharedString },
+    /// Delete the text in the range.
+    Delete { range: Range<usize> },
+    /// Replace the text in the range with the new text.
+    Replace {
+        range: Range<usize>,
+        text: SharedString,
+    },
+}
+
+impl EditOp {
+    pub(crate) fn new(range: Range<usize>, new_text: &str) -> Self {
+        if range.is_empty() {
+            Self::Insert {
+                offset: range.start,
+                text: new_text.to_string().into(),
+            }
+        } else if new_text.is_empty() {
+            Self::Delete { range }
+        } else {
+            Self::Replace {
+                range,
+                text: new_text.to_string().into(),
+            }
+        }
+    }
+
+    /// Returns the range of the text affected by the edit, before the edit.
+    pub fn range(&self) -> Range<usize> {
+        match self {
+            Self::Insert { offset, .. } => *offset..*offset,
+            Self::Delete { range } | Self::Replace { range, .. } => range.clone(),
+        }
+    }
+
+    /// Returns the inserted text, empty for [`EditOp::Delete`].
+    pub fn text(&self) -> &str {
+        match self {
+            Self::Insert { text, .. } | Self::Replace { text, .. } => text.as_ref(),
+            Self::Delete { .. } => "",
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct Change {
     pub(crate) old_range: Range<usize>,
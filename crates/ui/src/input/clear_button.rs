@@ -1,15 +1,57 @@
-use gpui::{App, Styled};
+use gpui::{px, App, Pixels, Styled};
+use rust_i18n::t;
 
 use crate::{
     button::{Button, ButtonVariants as _},
-    ActiveTheme as _, Icon, IconName, Sizable as _,
+    ActiveTheme as _, Icon, IconName, Sizable as _, Size,
 };
 
+/// Create a clear button, it's used in the `TextInput`, `DatePicker`, `Dropdown` to clear the value.
+///
+/// The returned [`Button`] is `xsmall` by default, you can change it by `with_size`,
+/// and use `on_click` to handle the clear action.
+///
+/// The button is labeled by the translated `Common.clear` in the tooltip,
+/// use `tooltip_with_action` to show the shortcut of the component.
+///
+/// ```ignore
+/// clear_button(cx)
+///     .small()
+///     .on_click(cx.listener(|view, _, window, cx| view.clear(window, cx)))
+/// ```
 #[inline]
-pub(crate) fn clear_button(cx: &App) -> Button {
+pub fn clear_button(cx: &App) -> Button {
     Button::new("clean")
         .icon(Icon::new(IconName::CircleX))
         .ghost()
         .xsmall()
+        .tooltip(t!("Common.clear"))
         .text_color(cx.theme().muted_foreground)
 }
+
+/// Create a clear button with the hit area scaled by the `size` of the component.
+///
+/// The icon keeps the same size as [`clear_button`], but the clickable area is enlarged
+/// (at least 24px) to be easy to hit on touch devices.
+/// The extra area is outside the layout, so the input height and the text position are not changed.
+pub fn sized_clear_button(size: impl Into<Size>, cx: &App) -> Button {
+    let hit_size = clear_button_hit_size(size.into());
+    // The xsmall icon button is 20px.
+    let offset = -(hit_size - px(20.)) / 2.;
+
+    clear_button(cx)
+        .min_w(hit_size)
+        .min_h(hit_size)
+        .mx(offset)
+        .my(offset)
+}
+
+#[inline]
+fn clear_button_hit_size(size: Size) -> Pixels {
+    match size {
+        Size::XSmall | Size::Small | Size::Medium => px(24.),
+        Size::Large => px(28.),
+        Size::XLarge => px(32.),
+        Size::Size(size) => size.max(px(24.)),
+    }
+}
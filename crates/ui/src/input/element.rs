@@ -150,7 +150,7 @@ impl TextElement {
                         ),
                         size(px(1.), cursor_height),
                     ),
-                    cx.theme().caret,
+                    input.caret_color.unwrap_or(cx.theme().caret),
                 ))
             };
         }
@@ -345,6 +345,15 @@ impl Element for TextElement {
         let input = self.input.read(cx);
         let mut style = Style::default();
         style.size.width = relative(1.).into();
+        if let Some(chars) = input.width_chars {
+            // Measure the width by the `0` character, like the `ch` unit in CSS.
+            let text_style = window.text_style();
+            let font_size = text_style.font_size.to_pixels(window.rem_size());
+            let font_id = window.text_system().resolve_font(&text_style.font());
+            if let Ok(advance) = window.text_system().advance(font_id, font_size, '0') {
+                style.size.width = (advance.width * chars as f32).into();
+            }
+        }
         if self.input.read(cx).is_multi_line() {
             style.flex_grow = 1.0;
             if let Some(h) = input.height {
@@ -379,7 +388,12 @@ impl Element for TextElement {
         let mut bounds = bounds;
 
         let (display_text, text_color) = if text.is_empty() {
-            (placeholder, cx.theme().muted_foreground)
+            (
+                placeholder,
+                input
+                    .placeholder_color
+                    .unwrap_or(cx.theme().muted_foreground),
+            )
         } else if input.masked {
             (
                 "*".repeat(text.chars().count()).into(),
@@ -528,7 +542,12 @@ impl Element for TextElement {
 
         // Paint selections
         if let Some(path) = prepaint.selection_path.take() {
-            window.paint_path(path, cx.theme().selection);
+            let selection_color = self
+                .input
+                .read(cx)
+                .selection_color
+                .unwrap_or(cx.theme().selection);
+            window.paint_path(path, selection_color);
         }
 
         // Paint multi line text
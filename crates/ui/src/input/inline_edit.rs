@@ -0,0 +1,300 @@
+use gpui::{
+    div, prelude::FluentBuilder as _, AppContext as _, ClickEvent, Context, Entity, EventEmitter,
+    InteractiveElement as _, IntoElement, ParentElement as _, Render, SharedString,
+    StatefulInteractiveElement as _, Styled as _, Subscription, Window,
+};
+
+use crate::{ActiveTheme as _, Sizable, Size, StyledExt as _};
+
+use super::{Escape, InputEvent, SelectAll, TextInput};
+
+pub enum InlineEditEvent {
+    /// The edited value is committed by Enter or blur, only emitted when the value is changed.
+    Change(SharedString),
+}
+
+/// A label that looks like a static text, and turns into a [`TextInput`] to edit it in place.
+///
+/// The edit is started by click (or double click), committed by Enter or blur,
+/// and reverted by Escape.
+///
+/// ```ignore
+/// let edit = cx.new(|cx| InlineEdit::new(window, cx).default_value("Untitled.txt"));
+/// cx.subscribe(&edit, |_, _, InlineEditEvent::Change(name), _| println!("Renamed: {}", name));
+/// ```
+pub struct InlineEdit {
+    input: Entity<TextInput>,
+    value: SharedString,
+    placeholder: SharedString,
+    editing: bool,
+    double_click: bool,
+    disabled: bool,
+    size: Size,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl InlineEdit {
+    pub fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let input = cx.new(|cx| TextInput::new(window, cx));
+
+        let _subscriptions =
+            vec![
+                cx.subscribe_in(&input, window, |this, _, event: &InputEvent, window, cx| {
+                    if let Some(StopEdit::Commit) = StopEdit::from_input_event(event) {
+                        this.commit(window, cx);
+                    }
+                }),
+            ];
+
+        Self {
+            input,
+            value: SharedString::default(),
+            placeholder: SharedString::default(),
+            editing: false,
+            double_click: false,
+            disabled: false,
+            size: Size::default(),
+            _subscriptions,
+        }
+    }
+
+    /// Set the default value of the inline edit.
+    pub fn default_value(mut self, value: impl Into<SharedString>) -> Self {
+        self.value = value.into();
+        self
+    }
+
+    /// Set the placeholder, it is displayed in the label and the input when the value is empty.
+    pub fn placeholder(
+        mut self,
+        placeholder: impl Into<SharedString>,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        self.placeholder = placeholder.into();
+        let placeholder = self.placeholder.clone();
+        self.input
+            .update(cx, |input, _| input.placeholder = placeholder);
+        self
+    }
+
+    /// Set true to start the edit by double click instead of click, default is `false`.
+    pub fn double_click(mut self, double_click: bool) -> Self {
+        self.double_click = double_click;
+        self
+    }
+
+    /// Set the disabled state, the edit can't be started if disabled, default is `false`.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set the value, the active edit is discarded.
+    pub fn set_value(
+        &mut self,
+        value: impl Into<SharedString>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.value = value.into();
+        self.stop_edit(window, cx);
+    }
+
+    /// Return the committed value.
+    pub fn value(&self) -> &SharedString {
+        &self.value
+    }
+
+    /// Return true if the input is displayed for editing.
+    pub fn is_editing(&self) -> bool {
+        self.editing
+    }
+
+    /// Start editing, the input is focused with the value selected.
+    pub fn start_edit(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.disabled || self.editing {
+            return;
+        }
+
+        self.editing = true;
+        let value = self.value.clone();
+        self.input.update(cx, |input, cx| {
+            input.set_text(value, window, cx);
+            input.focus(window, cx);
+            input.select_all(&SelectAll, window, cx);
+        });
+        cx.notify();
+    }
+
+    /// Commit the edited value, and emit [`InlineEditEvent::Change`] if it is changed.
+    pub fn commit(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.finish_edit(StopEdit::Commit, window, cx);
+    }
+
+    /// Discard the edited value, and display the previous value.
+    pub fn cancel(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.finish_edit(StopEdit::Cancel, window, cx);
+    }
+
+    fn finish_edit(&mut self, stop: StopEdit, window: &mut Window, cx: &mut Context<Self>) {
+        if !self.editing {
+            return;
+        }
+
+        let edited = self.input.read(cx).text().clone();
+        self.stop_edit(window, cx);
+        if let Some(value) = committed_value(stop, &self.value, &edited) {
+            self.value = value.clone();
+            cx.emit(InlineEditEvent::Change(value));
+        }
+    }
+
+    fn stop_edit(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let was_editing = std::mem::take(&mut self.editing);
+        if was_editing && self.input.read(cx).focus_handle.is_focused(window) {
+            window.blur();
+        }
+        cx.notify();
+    }
+
+    fn on_escape(&mut self, _: &Escape, window: &mut Window, cx: &mut Context<Self>) {
+        if !self.editing {
+            cx.propagate();
+            return;
+        }
+
+        cx.stop_propagation();
+        self.cancel(window, cx);
+    }
+
+    fn on_label_click(&mut self, event: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let click_count = if self.double_click { 2 } else { 1 };
+        if event.up.click_count == click_count {
+            self.start_edit(window, cx);
+        }
+    }
+}
+
+/// The way to stop the edit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StopEdit {
+    /// Keep the edited value, by Enter or blur.
+    Commit,
+    /// Revert to the previous value, by Escape.
+    Cancel,
+}
+
+impl StopEdit {
+    /// Returns the stop of the edit by the input event, the Escape is handled as an action.
+    fn from_input_event(event: &InputEvent) -> Option<Self> {
+        match event {
+            InputEvent::PressEnter { .. } | InputEvent::Blur => Some(Self::Commit),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the value to commit when the edit is stopped, `None` if canceled or not changed.
+fn committed_value(
+    stop: StopEdit,
+    value: &SharedString,
+    edited: &SharedString,
+) -> Option<SharedString> {
+    match stop {
+        StopEdit::Commit if edited != value => Some(edited.clone()),
+        _ => None,
+    }
+}
+
+impl Sizable for InlineEdit {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl EventEmitter<InlineEditEvent> for InlineEdit {}
+
+impl Render for InlineEdit {
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let size = self.size;
+        if self.editing {
+            self.input.update(cx, |input, _| input.size = size);
+
+            // Catch the Escape before the input, to revert instead of unselecting the text.
+            return div()
+                .capture_action(cx.listener(Self::on_escape))
+                .child(self.input.clone())
+                .into_any_element();
+        }
+
+        let is_empty = self.value.is_empty();
+        let label = if is_empty {
+            self.placeholder.clone()
+        } else {
+            self.value.clone()
+        };
+
+        // The same text styles with the `Link`.
+        div()
+            .id("inline-edit")
+            .map(|this| match size {
+                Size::XSmall => this.text_xs(),
+                Size::Small => this.text_sm(),
+                Size::Large => this.text_lg(),
+                Size::XLarge => this.text_xl(),
+                _ => this.text_base(),
+            })
+            .when(is_empty, |this| {
+                this.text_color(cx.theme().muted_foreground)
+            })
+            .when(!is_empty, |this| this.text_color(cx.theme().foreground))
+            .when(self.disabled, |this| this.disabled_style(cx))
+            .when(!self.disabled, |this| {
+                this.cursor_text()
+                    .hover(|this| {
+                        this.text_decoration_1()
+                            .text_decoration_color(cx.theme().link)
+                    })
+                    .on_click(cx.listener(Self::on_label_click))
+            })
+            .child(label)
+            .into_any_element()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::SharedString;
+
+    use super::{committed_value, StopEdit};
+    use crate::input::InputEvent;
+
+    #[test]
+    fn test_stop_edit_by_input_event() {
+        let enter = InputEvent::PressEnter { secondary: false };
+        assert_eq!(StopEdit::from_input_event(&enter), Some(StopEdit::Commit));
+        assert_eq!(
+            StopEdit::from_input_event(&InputEvent::Blur),
+            Some(StopEdit::Commit)
+        );
+        assert_eq!(StopEdit::from_input_event(&InputEvent::Focus), None);
+        let change = InputEvent::Change("a".into());
+        assert_eq!(StopEdit::from_input_event(&change), None);
+    }
+
+    #[test]
+    fn test_committed_value() {
+        let value = SharedString::from("Untitled.txt");
+        let edited = SharedString::from("README.md");
+
+        assert_eq!(
+            committed_value(StopEdit::Commit, &value, &edited),
+            Some(edited.clone())
+        );
+        // Escape reverts the edited value.
+        assert_eq!(committed_value(StopEdit::Cancel, &value, &edited), None);
+        // No change event for the unchanged value.
+        assert_eq!(committed_value(StopEdit::Commit, &value, &value), None);
+    }
+}
@@ -3,38 +3,43 @@
 //! Based on the `Input` example from the `gpui` crate.
 //! https://github.com/zed-industries/zed/blob/main/crates/gpui/examples/input.rs
 
+use rust_i18n::t;
 use serde::Deserialize;
 use smallvec::SmallVec;
 use std::cell::Cell;
 use std::ops::Range;
 use std::rc::Rc;
+use std::time::Duration;
 use unicode_segmentation::*;
 
 use gpui::prelude::FluentBuilder as _;
 use gpui::{
-    actions, div, impl_internal_actions, point, px, relative, AnyElement, App, AppContext, Bounds,
-    ClipboardItem, Context, DefiniteLength, Entity, EntityInputHandler, EventEmitter, FocusHandle,
-    Focusable, InteractiveElement as _, IntoElement, KeyBinding, KeyDownEvent, MouseButton,
-    MouseDownEvent, MouseMoveEvent, MouseUpEvent, ParentElement as _, Pixels, Point, Rems, Render,
-    ScrollHandle, ScrollWheelEvent, SharedString, Styled as _, Subscription, UTF16Selection,
+    actions, anchored, deferred, div, impl_internal_actions, point, px, relative, AnyElement, App,
+    AppContext, Bounds, ClipboardItem, Context, DefiniteLength, Entity, EntityInputHandler,
+    EventEmitter, FocusHandle, Focusable, Hsla, InteractiveElement as _, IntoElement, KeyBinding,
+    KeyDownEvent, MouseButton, MouseDownEvent, MouseMoveEvent, MouseUpEvent, ParentElement as _,
+    Pixels, Point, Rems, Render, ScrollHandle, ScrollWheelEvent, SharedString,
+    StatefulInteractiveElement as _, Styled as _, Subscription, Task, Timer, UTF16Selection,
     Window, WrappedLine,
 };
 
 // TODO:
 // - Move cursor to skip line eof empty chars.
 
-use super::blink_cursor::BlinkCursor;
-use super::change::Change;
+use super::blink_cursor::{self, BlinkCursor};
+use super::change::{Change, EditOp};
 use super::element::TextElement;
 use super::number_input;
 
 use crate::button::{Button, ButtonVariants as _};
+use crate::form::Validatable;
 use crate::history::History;
 use crate::indicator::Indicator;
-use crate::input::clear_button;
+use crate::input::sized_clear_button;
 use crate::scroll::{Scrollbar, ScrollbarAxis, ScrollbarState};
-use crate::{h_flex, StyledExt};
-use crate::{ActiveTheme, Root};
+use crate::tooltip::Tooltip;
+use crate::{h_flex, v_flex, Icon, StyledExt};
+use crate::{ActiveTheme, Root, TabStop};
 use crate::{IconName, Size};
 use crate::{Sizable, StyleSized};
 
@@ -84,6 +89,7 @@ actions!(
         MoveToEnd,
         MoveToPreviousWord,
         MoveToNextWord,
+        Clear,
         TextChanged,
         Escape
     ]
@@ -92,9 +98,40 @@ actions!(
 #[derive(Clone)]
 pub enum InputEvent {
     Change(SharedString),
-    PressEnter { secondary: bool },
+    /// The edit operation applied to the text, emitted before the [`InputEvent::Change`].
+    ///
+    /// This includes the edits by typing, IME, paste, undo/redo and `set_text`.
+    Edit(EditOp),
+    PressEnter {
+        secondary: bool,
+    },
     Focus,
     Blur,
+    /// The typed or pasted text is rejected by the `pattern` or `validate`, with the rejected text.
+    Rejected(SharedString),
+}
+
+/// The mode to mask the text of the password input.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum MaskMode {
+    /// Masked by [`TextInput::masked`], and revealed by [`TextInput::set_masked`]
+    /// or the toggle button, this is the default.
+    #[default]
+    Manual,
+    /// Reveal the text while the input is focused, and mask it again on blur.
+    ///
+    /// The text can only be selected and copied while focused, so the plain text is copied.
+    RevealOnFocus,
+}
+
+/// The behavior of the blur to the edit in progress, in the [`TextInput::commit_mode`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum BlurBehavior {
+    /// Commit the edit, like a spreadsheet cell, this is the default.
+    #[default]
+    Commit,
+    /// Revert the edit to the committed text.
+    Revert,
 }
 
 const CONTEXT: &str = "Input";
@@ -115,6 +152,10 @@ pub fn init(cx: &mut App) {
         KeyBinding::new("alt-delete", DeleteToNextWordEnd, Some(CONTEXT)),
         #[cfg(not(target_os = "macos"))]
         KeyBinding::new("ctrl-delete", DeleteToNextWordEnd, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-shift-backspace", Clear, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-shift-backspace", Clear, Some(CONTEXT)),
         KeyBinding::new("enter", Enter { secondary: false }, Some(CONTEXT)),
         KeyBinding::new("secondary-enter", Enter { secondary: true }, Some(CONTEXT)),
         KeyBinding::new("escape", Escape, Some(CONTEXT)),
@@ -207,10 +248,24 @@ pub struct TextInput {
     multi_line: bool,
     pub(super) history: History<Change>,
     pub(super) blink_cursor: Entity<BlinkCursor>,
+    /// The cursor blink interval, `None` to disable blinking.
+    cursor_blink: Option<Duration>,
     pub(super) prefix: Option<Box<dyn Fn(&mut Window, &mut Context<Self>) -> AnyElement + 'static>>,
+    /// The prefix handles the clicks by itself, e.g. a dropdown.
+    interactive_prefix: bool,
     pub(super) suffix: Option<Box<dyn Fn(&mut Window, &mut Context<Self>) -> AnyElement + 'static>>,
     pub(super) loading: bool,
+    /// The async validation is running, driven by the parent.
+    validating: bool,
+    /// The result of the async validation, the error message is displayed as tooltip.
+    validity: Option<Result<(), SharedString>>,
     pub(super) placeholder: SharedString,
+    /// The text color of the placeholder, default is `muted_foreground` of the theme.
+    pub(super) placeholder_color: Option<Hsla>,
+    /// The color of the cursor, default is `caret` of the theme.
+    pub(super) caret_color: Option<Hsla>,
+    /// The background color of the selected text, default is `selection` of the theme.
+    pub(super) selection_color: Option<Hsla>,
     /// Range in UTF-8 length for the selected text.
     ///
     /// - "Hello 世界💝" = 16
@@ -233,9 +288,31 @@ pub struct TextInput {
     pub(super) disabled: bool,
     pub(super) masked: bool,
     pub(super) mask_toggle: bool,
+    mask_mode: MaskMode,
+    copyable: bool,
+    copy_masked: bool,
+    /// The value has been copied just now to show the checkmark, and the task to reset it.
+    copied: Option<Task<()>>,
     pub(super) appearance: bool,
     pub(super) cleanable: bool,
     pub(super) clean_on_escape: bool,
+    /// Set to edit in the commit mode, the Enter commits the edit and the Escape reverts it.
+    commit_mode: bool,
+    blur_behavior: BlurBehavior,
+    /// The last committed text, to revert the edit in progress.
+    committed_text: SharedString,
+    /// Focus the input at the next render, reset after focused.
+    pub(super) autofocus: bool,
+    pub(super) tab_index: Option<isize>,
+    /// Blur the input when the Escape key is not handled by the input itself.
+    pub(super) blur_on_escape: bool,
+    /// Skip the `Edit` and `Change` events, for the value set by the parent component.
+    pub(super) silent: bool,
+    suggestions: Option<Box<dyn Fn(&str, &mut Window, &mut App) -> Vec<SharedString> + 'static>>,
+    /// The suggestions of the current text.
+    suggestion_items: Vec<SharedString>,
+    suggestion_ix: Option<usize>,
+    suggestions_open: bool,
     pub(super) size: Size,
     pub(super) rows: usize,
     pub(super) min_rows: usize,
@@ -243,8 +320,13 @@ pub struct TextInput {
     /// For special case, e.g.: NumberInput + - button
     pub(super) no_gap: bool,
     pub(super) height: Option<gpui::DefiniteLength>,
+    pub(super) width_chars: Option<usize>,
+    min_width: Option<Pixels>,
     pattern: Option<regex::Regex>,
     validate: Option<Box<dyn Fn(&str) -> bool + 'static>>,
+    /// The rule to check the text on submit, by [`Validatable::validate_field`].
+    rule: Option<Box<dyn Fn(&str) -> Result<(), SharedString> + 'static>>,
+    on_paste: Option<Box<dyn Fn(String) -> String + 'static>>,
     pub(crate) scroll_handle: ScrollHandle,
     scrollbar_state: Rc<Cell<ScrollbarState>>,
     /// The size of the scrollable content.
@@ -285,8 +367,12 @@ impl TextInput {
             text: "".into(),
             multi_line: false,
             blink_cursor,
+            cursor_blink: Some(blink_cursor::INTERVAL),
             history,
             placeholder: "".into(),
+            placeholder_color: None,
+            caret_color: None,
+            selection_color: None,
             selected_range: 0..0,
             selected_word_range: None,
             selection_reversed: false,
@@ -296,17 +382,39 @@ impl TextInput {
             disabled: false,
             masked: false,
             mask_toggle: false,
+            mask_mode: MaskMode::default(),
             appearance: true,
             cleanable: false,
             clean_on_escape: false,
+            commit_mode: false,
+            blur_behavior: BlurBehavior::default(),
+            committed_text: SharedString::default(),
+            autofocus: false,
+            tab_index: None,
+            blur_on_escape: true,
+            silent: false,
+            width_chars: None,
+            min_width: None,
+            copyable: false,
+            copy_masked: true,
+            copied: None,
+            suggestions: None,
+            suggestion_items: vec![],
+            suggestion_ix: None,
+            suggestions_open: false,
             loading: false,
+            validating: false,
+            validity: None,
             prefix: None,
+            interactive_prefix: false,
             suffix: None,
             no_gap: false,
             size: Size::Medium,
             height: None,
             pattern: None,
             validate: None,
+            rule: None,
+            on_paste: None,
             rows: 2,
             min_rows: 2,
             max_rows: None,
@@ -450,7 +558,7 @@ impl TextInput {
             prev_lines_offset += l.len() + 1;
         }
 
-        let new_offset = (prev_lines_offset + new_local_index).min(self.text.len());
+        let new_offset = self.snap_to_grapheme(prev_lines_offset + new_local_index);
         self.selected_range = new_offset..new_offset;
         self.pause_blink_cursor(cx);
         cx.notify();
@@ -536,6 +644,53 @@ impl TextInput {
         self.selected_range = self.selected_range.end..self.selected_range.end;
     }
 
+    /// Replace the selected text, and the replaced text will be selected.
+    pub fn replace_selection(
+        &mut self,
+        text: impl Into<SharedString>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let text: SharedString = text.into();
+        let start = self.selected_range.start;
+        self.replace_selection_and_select(&text, start..start + text.len(), window, cx);
+    }
+
+    /// Wrap the selected text with the prefix and suffix, e.g.: `**bold**`,
+    /// and the selected text (without the prefix and suffix) will be kept selected.
+    pub fn wrap_selection(
+        &mut self,
+        prefix: impl Into<SharedString>,
+        suffix: impl Into<SharedString>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let (prefix, suffix): (SharedString, SharedString) = (prefix.into(), suffix.into());
+        let selected_text = &self.text[self.selected_range.clone()];
+        let text = format!("{}{}{}", prefix, selected_text, suffix);
+        let start = self.selected_range.start + prefix.len();
+        let select_range = start..start + selected_text.len();
+        self.replace_selection_and_select(&text, select_range, window, cx);
+    }
+
+    fn replace_selection_and_select(
+        &mut self,
+        text: &str,
+        select_range: Range<usize>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let end = self.selected_range.start + text.len();
+        let range = self.range_to_utf16(&self.selected_range.clone());
+        self.replace_text_in_range(Some(range), text, window, cx);
+        // The cursor is moved to the end of the new text, unless the text is rejected by validation.
+        if self.selected_range == (end..end) {
+            self.selected_range = select_range;
+            self.selection_reversed = false;
+            cx.notify();
+        }
+    }
+
     fn replace_text(
         &mut self,
         text: impl Into<SharedString>,
@@ -565,12 +720,81 @@ impl TextInput {
         cx.notify();
     }
 
+    /// Set the mode to mask the text, default is `MaskMode::Manual`.
+    ///
+    /// The `MaskMode::RevealOnFocus` masks the text initially.
+    pub fn mask_mode(mut self, mode: MaskMode) -> Self {
+        self.mask_mode = mode;
+        if mode == MaskMode::RevealOnFocus {
+            self.masked = true;
+        }
+        self
+    }
+
     /// Set to enable toggle button for mask state.
     pub fn mask_toggle(mut self) -> Self {
         self.mask_toggle = true;
         self
     }
 
+    /// Set the width of the input to fit roughly the number of characters of the current font,
+    /// instead of filling the parent width.
+    ///
+    /// The characters are measured by the width of `0`, the prefix and paddings are added.
+    pub fn width_chars(mut self, chars: usize) -> Self {
+        self.width_chars = Some(chars);
+        self
+    }
+
+    /// Set the minimum width of the input, to avoid collapsing in a auto width layout.
+    pub fn min_width(mut self, width: impl Into<Pixels>) -> Self {
+        self.min_width = Some(width.into());
+        self
+    }
+
+    /// Set true to show a copy button at the input right, to copy the whole text to the clipboard.
+    ///
+    /// This is useful for the disabled input that displaying a generated token or URL.
+    pub fn copyable(mut self, copyable: bool) -> Self {
+        self.copyable = copyable;
+        self
+    }
+
+    /// Set false to hide the copy button when the input is masked, default is `true`.
+    ///
+    /// By default, the copy button copies the real text even when masked.
+    pub fn copy_masked(mut self, copy_masked: bool) -> Self {
+        self.copy_masked = copy_masked;
+        self
+    }
+
+    /// Set the cursor blink interval, `None` to show a solid caret without blinking.
+    ///
+    /// This is useful to respect the reduced motion preference.
+    ///
+    /// Default: 500ms
+    pub fn cursor_blink(mut self, interval: Option<Duration>) -> Self {
+        self.cursor_blink = interval;
+        self
+    }
+
+    /// Set the cursor blink interval with reference.
+    pub fn set_cursor_blink(
+        &mut self,
+        interval: Option<Duration>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.cursor_blink = interval;
+        let focused = self.focus_handle.is_focused(window);
+        self.blink_cursor.update(cx, |cursor, cx| {
+            cursor.set_interval(interval, cx);
+            if focused {
+                cursor.start(cx);
+            }
+        });
+    }
+
     /// Set the prefix element of the input field.
     pub fn set_prefix<F, E>(&mut self, builder: F, _: &mut Window, cx: &mut Context<Self>)
     where
@@ -608,6 +832,9 @@ impl TextInput {
     }
 
     /// Set the prefix element of the input field, for example a search Icon.
+    ///
+    /// Clicking the prefix focuses the input, use [`TextInput::interactive_prefix`] for
+    /// a prefix that handles the clicks by itself.
     pub fn prefix<F, E>(mut self, builder: F) -> Self
     where
         F: Fn(&mut Window, &mut Context<Self>) -> E + 'static,
@@ -619,6 +846,23 @@ impl TextInput {
         self
     }
 
+    /// Set true to keep the clicks to the prefix, e.g. a scope dropdown, default is `false`.
+    ///
+    /// The mouse down on the prefix will not move the cursor or focus the input.
+    ///
+    /// ```ignore
+    /// let scope = cx.new(|cx| Dropdown::new("scope", vec!["All", "Files"], Some(0), window, cx).small());
+    /// let input = cx.new(|cx| {
+    ///     TextInput::new(window, cx)
+    ///         .prefix(move |_, _| scope.clone())
+    ///         .interactive_prefix(true)
+    /// });
+    /// ```
+    pub fn interactive_prefix(mut self, interactive: bool) -> Self {
+        self.interactive_prefix = interactive;
+        self
+    }
+
     /// Set the suffix element of the input field, for example a clear button.
     pub fn suffix<F, E>(mut self, builder: F) -> Self
     where
@@ -660,6 +904,66 @@ impl TextInput {
         cx.notify();
     }
 
+    /// Set the text color of the placeholder, default is `muted_foreground` of the theme.
+    pub fn placeholder_color(mut self, color: impl Into<Hsla>) -> Self {
+        self.placeholder_color = Some(color.into());
+        self
+    }
+
+    /// Set the text color of the placeholder with reference.
+    pub fn set_placeholder_color(
+        &mut self,
+        color: Option<Hsla>,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.placeholder_color = color;
+        cx.notify();
+    }
+
+    /// Set the color of the cursor, default is `caret` of the theme.
+    pub fn caret_color(mut self, color: impl Into<Hsla>) -> Self {
+        self.caret_color = Some(color.into());
+        self
+    }
+
+    /// Set the color of the cursor with reference.
+    pub fn set_caret_color(&mut self, color: Option<Hsla>, _: &mut Window, cx: &mut Context<Self>) {
+        self.caret_color = color;
+        cx.notify();
+    }
+
+    /// Set the background color of the selected text, default is `selection` of the theme.
+    pub fn selection_color(mut self, color: impl Into<Hsla>) -> Self {
+        self.selection_color = Some(color.into());
+        self
+    }
+
+    /// Set the background color of the selected text with reference.
+    pub fn set_selection_color(
+        &mut self,
+        color: Option<Hsla>,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.selection_color = color;
+        cx.notify();
+    }
+
+    /// Set true to focus the input on the first render, default is `false`.
+    ///
+    /// If multiple components in the window set `autofocus`, the first in render order wins.
+    pub fn autofocus(mut self, autofocus: bool) -> Self {
+        self.autofocus = autofocus;
+        self
+    }
+
+    /// Set the tab index to define the focus order independent of the layout, see [`TabStop`].
+    pub fn tab_index(mut self, tab_index: isize) -> Self {
+        self.tab_index = Some(tab_index);
+        self
+    }
+
     /// Set true to show the clear button when the input field is not empty.
     pub fn cleanable(mut self) -> Self {
         self.cleanable = true;
@@ -667,8 +971,83 @@ impl TextInput {
     }
 
     /// Set true to clear the input by pressing Escape key.
-    pub fn clean_on_escape(mut self) -> Self {
-        self.clean_on_escape = true;
+    ///
+    /// The input is also blurred if it blurs on Escape, and the Escape key is propagated
+    /// to the parent only if the input is already empty.
+    pub fn clean_on_escape(self) -> Self {
+        self.clear_on_escape(true)
+    }
+
+    /// Set to clear the input by pressing Escape key, default is false.
+    ///
+    /// See [`TextInput::clean_on_escape`].
+    pub fn clear_on_escape(mut self, clear_on_escape: bool) -> Self {
+        self.clean_on_escape = clear_on_escape;
+        self
+    }
+
+    /// Set to edit in the commit mode, like a spreadsheet cell, default is `false`.
+    ///
+    /// The text when the input is focused is kept as the committed text, the Enter key commits
+    /// the edit and the Escape key reverts it and blurs. The blur is decided by the
+    /// [`TextInput::blur_behavior`].
+    pub fn commit_mode(mut self, commit_mode: bool) -> Self {
+        self.commit_mode = commit_mode;
+        self
+    }
+
+    /// Set to commit or revert the edit on blur in the commit mode, default is `BlurBehavior::Commit`.
+    pub fn blur_behavior(mut self, behavior: BlurBehavior) -> Self {
+        self.blur_behavior = behavior;
+        self
+    }
+
+    /// Revert the edit in progress to the text when the editing began (or was last committed).
+    pub fn revert(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.text == self.committed_text {
+            return;
+        }
+
+        let text = self.committed_text.clone();
+        self.replace_text(text, window, cx);
+        self.selected_range = 0..0;
+        cx.notify();
+    }
+
+    /// Set the suggestions to display in a dropdown below the input, only for the single line input.
+    ///
+    /// The suggestions containing the typed text (case-insensitive) will be displayed,
+    /// use `up` and `down` to navigate, `enter` or click to complete the text.
+    pub fn suggestions(self, suggestions: Vec<SharedString>) -> Self {
+        self.suggestions_fn(move |text, _, _| {
+            let query = text.to_lowercase();
+            suggestions
+                .iter()
+                .filter(|item| {
+                    let item = item.to_lowercase();
+                    item.contains(&query) && item != query
+                })
+                .cloned()
+                .collect()
+        })
+    }
+
+    /// Set a function to produce the suggestions from the typed text, see [`TextInput::suggestions`].
+    ///
+    /// The function is called when the text is changed by the user, not called for the empty text.
+    pub fn suggestions_fn(
+        mut self,
+        f: impl Fn(&str, &mut Window, &mut App) -> Vec<SharedString> + 'static,
+    ) -> Self {
+        self.suggestions = Some(Box::new(f));
+        self
+    }
+
+    /// Set false to keep focus when the Escape key is propagated, default is `true`.
+    ///
+    /// This used by the components that handle the Escape by themselves (e.g. close the popup).
+    pub(crate) fn blur_on_escape(mut self, blur_on_escape: bool) -> Self {
+        self.blur_on_escape = blur_on_escape;
         self
     }
 
@@ -702,6 +1081,50 @@ impl TextInput {
         self
     }
 
+    /// Set the rule to check the text on submit, e.g. required or the email format.
+    ///
+    /// Unlike `validate` to reject the typing, the text is checked by the [`FormValidator`](crate::form::FormValidator),
+    /// and the error is displayed like [`TextInput::set_validity`].
+    pub fn rule(mut self, rule: impl Fn(&str) -> Result<(), SharedString> + 'static) -> Self {
+        self.rule = Some(Box::new(rule));
+        self
+    }
+
+    /// Set a function to transform the clipboard text before pasting, return an empty string to cancel.
+    ///
+    /// The `pattern` and `validate` are checked with the transformed text.
+    pub fn on_paste(mut self, f: impl Fn(String) -> String + 'static) -> Self {
+        self.on_paste = Some(Box::new(f));
+        self
+    }
+
+    /// Set the async validation is running, to show a spinner in the suffix.
+    pub fn set_validating(&mut self, validating: bool, _: &mut Window, cx: &mut Context<Self>) {
+        self.validating = validating;
+        cx.notify();
+    }
+
+    /// Set the result of the async validation, to show a check or error icon in the suffix.
+    ///
+    /// Set `None` to clear the result.
+    pub fn set_validity(
+        &mut self,
+        validity: Option<Result<(), SharedString>>,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.validity = validity;
+        cx.notify();
+    }
+
+    /// Return the error message of the validation result set by [`TextInput::set_validity`].
+    pub fn validation_error(&self) -> Option<&SharedString> {
+        match self.validity.as_ref()? {
+            Ok(()) => None,
+            Err(message) => Some(message),
+        }
+    }
+
     /// Set true to show indicator at the input right.
     pub fn set_loading(&mut self, loading: bool, _: &mut Window, cx: &mut Context<Self>) {
         self.loading = loading;
@@ -713,6 +1136,21 @@ impl TextInput {
         &self.text
     }
 
+    /// Return the number of words in the text, separated by whitespace.
+    ///
+    /// The empty text has 0 words.
+    pub fn word_count(&self) -> usize {
+        self.text.split_whitespace().count()
+    }
+
+    /// Return the number of lines in the text, the same as the lines displayed in the input.
+    ///
+    /// The empty text has 1 line, and a trailing newline starts a new (empty) line,
+    /// e.g. `"Hello\n"` has 2 lines.
+    pub fn line_count(&self) -> usize {
+        self.text.split('\n').count()
+    }
+
     pub fn disabled(&self) -> bool {
         self.disabled
     }
@@ -780,7 +1218,12 @@ impl TextInput {
         self.select_to(self.next_boundary(offset), window, cx);
     }
 
-    fn select_all(&mut self, _: &SelectAll, window: &mut Window, cx: &mut Context<Self>) {
+    pub(super) fn select_all(
+        &mut self,
+        _: &SelectAll,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
         self.move_to(0, window, cx);
         self.select_to(self.text.len(), window, cx)
     }
@@ -812,6 +1255,7 @@ impl TextInput {
         window: &mut Window,
         cx: &mut Context<Self>,
     ) {
+        self.pause_blink_cursor(cx);
         let offset = self.previous_start_of_word();
         self.move_to(offset, window, cx);
     }
@@ -822,6 +1266,7 @@ impl TextInput {
         window: &mut Window,
         cx: &mut Context<Self>,
     ) {
+        self.pause_blink_cursor(cx);
         let offset = self.next_end_of_word();
         self.move_to(offset, window, cx);
     }
@@ -842,7 +1287,7 @@ impl TextInput {
         cx: &mut Context<Self>,
     ) {
         let offset = self.start_of_line(window, cx);
-        self.select_to(self.previous_boundary(offset), window, cx);
+        self.select_to(offset, window, cx);
     }
 
     fn select_to_end_of_line(
@@ -852,7 +1297,7 @@ impl TextInput {
         cx: &mut Context<Self>,
     ) {
         let offset = self.end_of_line(window, cx);
-        self.select_to(self.next_boundary(offset), window, cx);
+        self.select_to(offset, window, cx);
     }
 
     fn select_to_previous_word(
@@ -875,9 +1320,9 @@ impl TextInput {
         self.select_to(offset, window, cx);
     }
 
-    /// Return the start offset of the previous word.
+    /// Return the start offset of the previous word, from the cursor.
     fn previous_start_of_word(&mut self) -> usize {
-        let offset = self.selected_range.start;
+        let offset = self.cursor_offset();
         let prev_str = &self.text[..offset].to_string();
         UnicodeSegmentation::split_word_bound_indices(prev_str as &str)
             .filter(|(_, s)| !s.trim_start().is_empty())
@@ -1035,6 +1480,11 @@ impl TextInput {
     }
 
     fn enter(&mut self, action: &Enter, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(ix) = self.suggestion_ix.filter(|_| self.suggestions_open) {
+            self.complete_suggestion(ix, window, cx);
+            return;
+        }
+
         if self.is_multi_line() {
             let is_eof = self.selected_range.end == self.text.len();
             self.replace_text_in_range(None, "\n", window, cx);
@@ -1047,11 +1497,132 @@ impl TextInput {
             self.move_to(new_offset, window, cx);
         }
 
+        if self.commit_mode {
+            self.committed_text = self.text.clone();
+        }
         cx.emit(InputEvent::PressEnter {
             secondary: action.secondary,
         });
     }
 
+    /// Update the suggestions of the typed text, only when the input is focused.
+    fn update_suggestions(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(suggestions) = self.suggestions.as_ref() else {
+            return;
+        };
+        if self.is_multi_line() || !self.focus_handle.is_focused(window) {
+            return;
+        }
+
+        self.suggestion_items = if self.text.is_empty() {
+            vec![]
+        } else {
+            suggestions(&self.text, window, cx)
+        };
+        self.suggestion_ix = None;
+        self.suggestions_open = !self.suggestion_items.is_empty();
+    }
+
+    fn close_suggestions(&mut self, cx: &mut Context<Self>) {
+        if !self.suggestions_open {
+            return;
+        }
+
+        self.suggestions_open = false;
+        self.suggestion_ix = None;
+        cx.notify();
+    }
+
+    fn complete_suggestion(&mut self, ix: usize, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(item) = self.suggestion_items.get(ix).cloned() else {
+            return;
+        };
+
+        self.replace_text(item, window, cx);
+        self.suggestions_open = false;
+        self.suggestion_ix = None;
+        cx.notify();
+    }
+
+    fn prev_suggestion(&mut self, _: &Up, _: &mut Window, cx: &mut Context<Self>) {
+        if !self.suggestions_open {
+            cx.propagate();
+            return;
+        }
+
+        let len = self.suggestion_items.len();
+        self.suggestion_ix = Some(match self.suggestion_ix {
+            Some(ix) if ix > 0 => ix - 1,
+            _ => len - 1,
+        });
+        cx.notify();
+    }
+
+    fn next_suggestion(&mut self, _: &Down, _: &mut Window, cx: &mut Context<Self>) {
+        if !self.suggestions_open {
+            cx.propagate();
+            return;
+        }
+
+        let len = self.suggestion_items.len();
+        self.suggestion_ix = Some(match self.suggestion_ix {
+            Some(ix) if ix + 1 < len => ix + 1,
+            _ => 0,
+        });
+        cx.notify();
+    }
+
+    fn render_suggestions(&self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        div().absolute().top(relative(1.)).left_0().child(
+            deferred(
+                anchored().snap_to_window_with_margin(px(8.)).child(
+                    v_flex()
+                        .id("suggestions")
+                        .occlude()
+                        .mt_1()
+                        .p_1()
+                        .min_w(px(120.))
+                        .w(self.input_bounds.size.width)
+                        .max_h(px(240.))
+                        .overflow_y_scroll()
+                        .bg(cx.theme().popover)
+                        .text_color(cx.theme().popover_foreground)
+                        .border_1()
+                        .border_color(cx.theme().border)
+                        .rounded(cx.theme().radius)
+                        .shadow_md()
+                        .input_text_size(self.size)
+                        .on_mouse_down_out(cx.listener(|this, _, _, cx| {
+                            this.close_suggestions(cx);
+                        }))
+                        .children(self.suggestion_items.iter().enumerate().map(|(ix, item)| {
+                            let selected = self.suggestion_ix == Some(ix);
+
+                            h_flex()
+                                .id(ix)
+                                .px_2()
+                                .py_1()
+                                .rounded(cx.theme().radius)
+                                .hover(|this| this.bg(cx.theme().accent))
+                                .when(selected, |this| {
+                                    this.bg(cx.theme().accent)
+                                        .text_color(cx.theme().accent_foreground)
+                                })
+                                .on_mouse_down(
+                                    MouseButton::Left,
+                                    cx.listener(move |this, _, window, cx| {
+                                        cx.stop_propagation();
+                                        this.complete_suggestion(ix, window, cx);
+                                    }),
+                                )
+                                .child(item.clone())
+                        })),
+                ),
+            )
+            .with_priority(1),
+        )
+    }
+
     fn check_to_auto_grow(&mut self, _: &mut Window, cx: &mut Context<Self>) {
         if !self.is_multi_line() {
             return;
@@ -1073,15 +1644,42 @@ impl TextInput {
         self.replace_text("", window, cx);
     }
 
+    /// Clear the text by the shortcut like the clear button, only if `cleanable` is enabled.
+    fn on_action_clear(&mut self, _: &Clear, window: &mut Window, cx: &mut Context<Self>) {
+        if !self.cleanable || self.text.is_empty() {
+            cx.propagate();
+            return;
+        }
+
+        self.clean(window, cx);
+    }
+
     fn escape(&mut self, _: &Escape, window: &mut Window, cx: &mut Context<Self>) {
+        if self.suggestions_open {
+            return self.close_suggestions(cx);
+        }
+
+        if self.commit_mode {
+            self.revert(window, cx);
+            window.blur();
+            return;
+        }
+
         if self.selected_range.len() > 0 {
             return self.unselect(window, cx);
         }
 
-        if self.clean_on_escape {
-            return self.clean(window, cx);
+        if self.clean_on_escape && !self.text.is_empty() {
+            self.clean(window, cx);
+            if self.blur_on_escape {
+                window.blur();
+            }
+            return;
         }
 
+        if self.blur_on_escape {
+            window.blur();
+        }
         cx.propagate();
     }
 
@@ -1093,6 +1691,12 @@ impl TextInput {
     ) {
         self.selecting = true;
         let offset = self.index_for_mouse_position(event.position, window, cx);
+        // Triple click to select line
+        if event.button == MouseButton::Left && event.click_count >= 3 {
+            self.select_line(offset, window, cx);
+            return;
+        }
+
         // Double click to select word
         if event.button == MouseButton::Left && event.click_count == 2 {
             self.select_word(offset, window, cx);
@@ -1166,6 +1770,12 @@ impl TextInput {
     fn paste(&mut self, _: &Paste, window: &mut Window, cx: &mut Context<Self>) {
         if let Some(clipboard) = cx.read_from_clipboard() {
             let mut new_text = clipboard.text().unwrap_or_default();
+            if let Some(on_paste) = self.on_paste.as_ref() {
+                new_text = on_paste(new_text);
+                if new_text.is_empty() {
+                    return;
+                }
+            }
             if !self.multi_line {
                 new_text = new_text.replace('\n', "");
             }
@@ -1282,7 +1892,7 @@ impl TextInput {
 
             // Return offset by use closest_index_for_x if is single line mode.
             if self.is_single_line() {
-                return closest_index;
+                return self.snap_to_grapheme(closest_index);
             }
 
             let index_result = line.closest_index_for_position(pos, line_height);
@@ -1312,11 +1922,7 @@ impl TextInput {
             index += 1;
         }
 
-        if index > self.text.len() {
-            self.text.len()
-        } else {
-            index
-        }
+        self.snap_to_grapheme(index)
     }
 
     /// Returns a y offsetted point for the line origin.
@@ -1414,6 +2020,28 @@ impl TextInput {
         cx.notify()
     }
 
+    /// Select the line at the given offset, or all text for the single line input.
+    ///
+    /// The offset is the UTF-8 offset.
+    fn select_line(&mut self, offset: usize, _: &mut Window, cx: &mut Context<Self>) {
+        let offset = offset.min(self.text.len());
+        let (start, end) = if self.is_multi_line() {
+            let start = self.text[..offset].rfind('\n').map_or(0, |ix| ix + 1);
+            let end = self.text[offset..]
+                .find('\n')
+                .map_or(self.text.len(), |ix| offset + ix);
+            (start, end)
+        } else {
+            (0, self.text.len())
+        };
+
+        self.selected_range = start..end;
+        self.selection_reversed = false;
+        // Keep the line selected when drag move, like the word.
+        self.selected_word_range = Some(self.selected_range.clone());
+        cx.notify()
+    }
+
     fn unselect(&mut self, _: &mut Window, cx: &mut Context<Self>) {
         let offset = self.next_boundary(self.cursor_offset());
         self.selected_range = offset..offset;
@@ -1473,26 +2101,68 @@ impl TextInput {
             .unwrap_or(self.text.len())
     }
 
+    /// Snap the offset to the nearest grapheme boundary, to avoid the cursor landing
+    /// in the middle of an emoji (e.g. "👨‍👩‍👧") or a combining sequence.
+    fn snap_to_grapheme(&self, offset: usize) -> usize {
+        let offset = offset.min(self.text.len());
+        let mut prev = 0;
+        for (ix, _) in self.text.grapheme_indices(true) {
+            if ix == offset {
+                return offset;
+            }
+            if ix > offset {
+                return if offset - prev < ix - offset {
+                    prev
+                } else {
+                    ix
+                };
+            }
+            prev = ix;
+        }
+
+        if offset - prev < self.text.len() - offset {
+            prev
+        } else {
+            self.text.len()
+        }
+    }
+
     /// Returns the true to let InputElement to render cursor, when Input is focused and current BlinkCursor is visible.
     pub(crate) fn show_cursor(&self, window: &Window, cx: &App) -> bool {
         self.focus_handle.is_focused(window) && self.blink_cursor.read(cx).visible()
     }
 
     fn on_focus(&mut self, _: &mut Window, cx: &mut Context<Self>) {
+        let interval = self.cursor_blink;
         self.blink_cursor.update(cx, |cursor, cx| {
+            cursor.set_interval(interval, cx);
             cursor.start(cx);
         });
+        if self.mask_mode == MaskMode::RevealOnFocus {
+            self.masked = false;
+            cx.notify();
+        }
+        self.committed_text = self.text.clone();
         cx.emit(InputEvent::Focus);
     }
 
     fn on_blur(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.commit_mode && self.blur_behavior == BlurBehavior::Revert {
+            self.revert(window, cx);
+        }
+        self.committed_text = self.text.clone();
         self.unselect(window, cx);
+        self.close_suggestions(cx);
         self.blink_cursor.update(cx, |cursor, cx| {
             cursor.stop(cx);
         });
         Root::update(window, cx, |root, _, _| {
             root.focused_input = None;
         });
+        if self.mask_mode == MaskMode::RevealOnFocus {
+            self.masked = true;
+            cx.notify();
+        }
         cx.emit(InputEvent::Blur);
     }
 
@@ -1549,6 +2219,71 @@ impl TextInput {
             .unwrap_or(true)
     }
 
+    fn render_validity(&self, _: &mut Window, cx: &mut Context<Self>) -> Option<AnyElement> {
+        if self.validating {
+            return Some(
+                Indicator::new()
+                    .xsmall()
+                    .color(cx.theme().muted_foreground)
+                    .into_any_element(),
+            );
+        }
+
+        match self.validity.clone()? {
+            Ok(()) => Some(
+                Icon::new(IconName::Check)
+                    .xsmall()
+                    .text_color(cx.theme().success)
+                    .into_any_element(),
+            ),
+            Err(message) => Some(
+                div()
+                    .id("validity")
+                    .child(
+                        Icon::new(IconName::CircleX)
+                            .xsmall()
+                            .text_color(cx.theme().danger),
+                    )
+                    .tooltip(move |window, cx| Tooltip::new(message.clone()).build(window, cx))
+                    .into_any_element(),
+            ),
+        }
+    }
+
+    fn render_copy_button(
+        &self,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Option<impl IntoElement> {
+        if !self.copyable || (self.masked && !self.copy_masked) {
+            return None;
+        }
+
+        Some(
+            Button::new("copy")
+                .icon(if self.copied.is_some() {
+                    IconName::Check
+                } else {
+                    IconName::Copy
+                })
+                .xsmall()
+                .ghost()
+                .on_click(cx.listener(|this, _, _, cx| {
+                    cx.stop_propagation();
+                    cx.write_to_clipboard(ClipboardItem::new_string(this.text.to_string()));
+                    // Replace the previous task, to show the checkmark for 2s after the last click.
+                    this.copied = Some(cx.spawn(async move |this, cx| {
+                        Timer::after(Duration::from_secs(2)).await;
+                        _ = this.update(cx, |this, cx| {
+                            this.copied = None;
+                            cx.notify();
+                        });
+                    }));
+                    cx.notify();
+                })),
+        )
+    }
+
     fn render_toggle_mask_button(
         &self,
         _: &mut Window,
@@ -1645,17 +2380,25 @@ impl EntityInputHandler for TextInput {
         let pending_text: SharedString =
             (self.text[0..range.start].to_owned() + new_text + &self.text[range.end..]).into();
         if !self.is_valid_input(&pending_text) {
+            if !self.silent {
+                cx.emit(InputEvent::Rejected(new_text.to_string().into()));
+            }
             return;
         }
 
         self.push_history(&range, new_text, window, cx);
         self.text = pending_text;
         self.selected_range = range.start + new_text.len()..range.start + new_text.len();
+        let edit = EditOp::new(range, new_text);
         self.marked_range.take();
         self.update_preferred_x_offset(cx);
         self.update_scroll_offset(None, cx);
         self.check_to_auto_grow(window, cx);
-        cx.emit(InputEvent::Change(self.text.clone()));
+        self.update_suggestions(window, cx);
+        if !self.silent {
+            cx.emit(InputEvent::Edit(edit));
+            cx.emit(InputEvent::Change(self.text.clone()));
+        }
         cx.notify();
     }
 
@@ -1679,6 +2422,9 @@ impl EntityInputHandler for TextInput {
         let pending_text: SharedString =
             (self.text[0..range.start].to_owned() + new_text + &self.text[range.end..]).into();
         if !self.is_valid_input(&pending_text) {
+            if !self.silent {
+                cx.emit(InputEvent::Rejected(new_text.to_string().into()));
+            }
             return;
         }
 
@@ -1690,7 +2436,11 @@ impl EntityInputHandler for TextInput {
             .map(|range_utf16| self.range_from_utf16(range_utf16))
             .map(|new_range| new_range.start + range.start..new_range.end + range.end)
             .unwrap_or_else(|| range.start + new_text.len()..range.start + new_text.len());
-        cx.emit(InputEvent::Change(self.text.clone()));
+        self.update_suggestions(window, cx);
+        if !self.silent {
+            cx.emit(InputEvent::Edit(EditOp::new(range, new_text)));
+            cx.emit(InputEvent::Change(self.text.clone()));
+        }
         cx.notify();
     }
 
@@ -1762,19 +2512,48 @@ impl EntityInputHandler for TextInput {
     }
 }
 
+impl Validatable for TextInput {
+    /// Check the text by the `rule`, or return the result set by [`TextInput::set_validity`].
+    fn validate_field(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Result<(), SharedString> {
+        if let Some(rule) = self.rule.as_ref() {
+            let result = rule(&self.text);
+            self.set_validity(Some(result), window, cx);
+        }
+
+        match self.validation_error() {
+            Some(message) => Err(message.clone()),
+            None => Ok(()),
+        }
+    }
+}
+
 impl Focusable for TextInput {
     fn focus_handle(&self, _cx: &App) -> FocusHandle {
         self.focus_handle.clone()
     }
 }
 
+impl TabStop for TextInput {
+    fn tab_stop_index(&self, _: &App) -> Option<isize> {
+        self.tab_index
+    }
+}
+
 impl Render for TextInput {
     fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         const LINE_HEIGHT: Rems = Rems(1.25);
+        if std::mem::take(&mut self.autofocus) && !self.disabled {
+            crate::focusable::autofocus(&self.focus_handle, window, cx);
+        }
         let focused = self.focus_handle.is_focused(window);
         let mut gap_x = match self.size {
             Size::Small => px(4.),
             Size::Large => px(8.),
+            Size::XLarge => px(10.),
             _ => px(4.),
         };
         if self.no_gap {
@@ -1785,11 +2564,11 @@ impl Render for TextInput {
         let suffix = self.suffix.as_ref().map(|build| build(window, cx));
         let show_clear_button =
             self.cleanable && !self.loading && !self.text.is_empty() && self.is_single_line();
-        let bg = if self.disabled {
-            cx.theme().muted
-        } else {
-            cx.theme().background
-        };
+        let bg = cx.theme().background;
+        // Mirror the prefix and suffix in right-to-left. Only the layout is mirrored, the text is
+        // shaped by GPUI without the bidirectional reordering, so the caret and selection follow
+        // the logical order.
+        let rtl = crate::direction(cx).is_rtl();
 
         div()
             .flex()
@@ -1803,6 +2582,7 @@ impl Render for TextInput {
                     .on_action(cx.listener(Self::delete_to_end_of_line))
                     .on_action(cx.listener(Self::delete_previous_word))
                     .on_action(cx.listener(Self::delete_next_word))
+                    .on_action(cx.listener(Self::on_action_clear))
                     .on_action(cx.listener(Self::enter))
                     .on_action(cx.listener(Self::escape))
             })
@@ -1816,6 +2596,13 @@ impl Render for TextInput {
                     .on_action(cx.listener(Self::select_up))
                     .on_action(cx.listener(Self::select_down))
             })
+            .when(
+                self.is_single_line() && self.suggestions.is_some(),
+                |this| {
+                    this.on_action(cx.listener(Self::prev_suggestion))
+                        .on_action(cx.listener(Self::next_suggestion))
+                },
+            )
             .on_action(cx.listener(Self::select_all))
             .on_action(cx.listener(Self::select_to_start_of_line))
             .on_action(cx.listener(Self::select_to_end_of_line))
@@ -1841,10 +2628,13 @@ impl Render for TextInput {
             .on_mouse_up(MouseButton::Left, cx.listener(Self::on_mouse_up))
             .on_scroll_wheel(cx.listener(Self::on_scroll_wheel))
             .size_full()
+            .when(self.width_chars.is_some(), |this| this.w_auto().flex_none())
+            .when_some(self.min_width, |this, width| this.min_w(width))
             .line_height(LINE_HEIGHT)
-            .input_py(self.size)
+            .input_py_with_density(self.size, cx.theme().density)
             .input_h(self.size)
             .cursor_text()
+            .when(self.disabled, |this| this.disabled_style(cx))
             .when(self.multi_line, |this| {
                 this.h_auto()
                     .when_some(self.height, |this, height| this.h(height))
@@ -1856,12 +2646,33 @@ impl Render for TextInput {
                     .rounded(cx.theme().radius)
                     .when(cx.theme().shadow, |this| this.shadow_sm())
                     .when(focused, |this| this.focused_border(cx))
+                    .when(matches!(self.validity, Some(Err(_))), |this| {
+                        this.border_color(cx.theme().danger)
+                    })
             })
-            .when(prefix.is_none(), |this| this.input_pl(self.size))
-            .input_pr(self.size)
+            .when(prefix.is_none(), |this| {
+                this.input_pl_with_density(self.size, cx.theme().density)
+            })
+            .input_pr_with_density(self.size, cx.theme().density)
             .items_center()
             .gap(gap_x)
-            .children(prefix)
+            .when(rtl, |this| this.flex_row_reverse())
+            .children(prefix.map(|prefix| {
+                if !self.interactive_prefix {
+                    return prefix;
+                }
+
+                div()
+                    .id("prefix")
+                    .flex()
+                    .flex_none()
+                    .items_center()
+                    .cursor_default()
+                    // Keep the clicks to the prefix, not to move the cursor or focus the text.
+                    .on_mouse_down(MouseButton::Left, |_, _, cx| cx.stop_propagation())
+                    .child(prefix)
+                    .into_any_element()
+            }))
             .child(
                 div()
                     .id("text-element")
@@ -1879,20 +2690,27 @@ impl Render for TextInput {
                     .when(self.appearance, |this| this.bg(bg))
                     .items_center()
                     .when(suffix.is_none(), |this| this.pr_1())
-                    .right_0()
+                    .map(|this| if rtl { this.left_0() } else { this.right_0() })
                     .when(self.loading, |this| {
                         this.child(Indicator::new().color(cx.theme().muted_foreground))
                     })
+                    .children(self.render_validity(window, cx))
                     .children(self.render_toggle_mask_button(window, cx))
+                    .children(self.render_copy_button(window, cx))
                     .when(show_clear_button, |this| {
                         this.child(
-                            clear_button(cx).on_click(cx.listener(|view, _, window, cx| {
-                                view.clean(window, cx);
-                            })),
+                            sized_clear_button(self.size, cx)
+                                .tooltip_with_action(t!("Common.clear"), &Clear, Some(CONTEXT))
+                                .on_click(cx.listener(|view, _, window, cx| {
+                                    view.clean(window, cx);
+                                })),
                         )
                     })
                     .children(suffix),
             )
+            .when(self.suggestions_open, |this| {
+                this.child(self.render_suggestions(window, cx))
+            })
             .when(self.is_multi_line(), |this| {
                 let entity_id = cx.entity().entity_id();
                 if self.last_layout.is_some() {
@@ -2,11 +2,17 @@ mod blink_cursor;
 mod change;
 mod clear_button;
 mod element;
+mod inline_edit;
 mod input;
 mod number_input;
 mod otp_input;
 
-pub(crate) use clear_button::*;
+pub use change::EditOp;
+pub use clear_button::*;
+pub use inline_edit::*;
 pub use input::*;
-pub use number_input::{NumberInput, NumberInputEvent, StepAction};
+pub(crate) use number_input::{clamp_between, round_to_step, subscriber_step};
+pub use number_input::{
+    ButtonsLayout, Notation, NumberFormat, NumberInput, NumberInputEvent, StepAction,
+};
 pub use otp_input::*;
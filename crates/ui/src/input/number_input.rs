@@ -1,21 +1,34 @@
+use std::time::Duration;
+
 use gpui::{
     actions, prelude::FluentBuilder as _, px, App, AppContext as _, Context, Entity, EventEmitter,
-    FocusHandle, Focusable, InteractiveElement, IntoElement, KeyBinding, ParentElement, Render,
-    SharedString, Styled, Subscription, Window,
+    FocusHandle, Focusable, InteractiveElement, IntoElement, KeyBinding, ParentElement, Pixels,
+    Render, ScrollWheelEvent, SharedString, Styled, Subscription, Task, Timer, Window,
 };
 use regex::Regex;
 
 use crate::{
     button::{Button, ButtonVariants as _},
+    form::Validatable,
     h_flex,
     input::{InputEvent, TextInput},
-    ActiveTheme, IconName, Sizable, Size, StyleSized, StyledExt as _,
+    v_flex, ActiveTheme, IconName, Sizable, Size, StyleSized, StyledExt as _, TabStop,
 };
 
 actions!(number_input, [Increment, Decrement]);
 
 const KEY_CONTENT: &str = "NumberInput";
 
+/// The duration to highlight the input after the value is clamped, when `clamp_feedback` is enabled.
+const CLAMP_FEEDBACK_DURATION: Duration = Duration::from_millis(1200);
+
+/// The duration and frames to animate the value changed by `set_value`, when `animate_changes` is enabled.
+const ANIMATION_DURATION: Duration = Duration::from_millis(240);
+const ANIMATION_FRAMES: u32 = 12;
+
+/// The scrolled distance of the mouse wheel to step once, when `wheel_step` is enabled.
+const WHEEL_STEP_DELTA: Pixels = px(20.);
+
 pub fn init(cx: &mut App) {
     cx.bind_keys(vec![
         KeyBinding::new("up", Increment, Some(KEY_CONTENT)),
@@ -26,14 +39,36 @@ pub fn init(cx: &mut App) {
 pub struct NumberInput {
     input: Entity<TextInput>,
     size: Size,
+    on_change: Option<Box<dyn Fn(&SharedString, &mut Window, &mut App) + 'static>>,
+    min: Option<f64>,
+    max: Option<f64>,
+    /// The step applied by the step actions, `None` to leave it to the subscriber.
+    step: Option<f64>,
+    wrap: bool,
+    notation: Notation,
+    /// The separators to parse and display the value, `None` to follow the locale.
+    number_format: Option<NumberFormat>,
+    /// The number format of the current pattern, to update the pattern when the locale changes.
+    pattern_format: Option<NumberFormat>,
+    use_grouping: bool,
+    buttons_layout: ButtonsLayout,
+    wheel_step: bool,
+    /// The scrolled distance of the mouse wheel not stepped yet.
+    wheel_delta: Pixels,
+    clamp_feedback: bool,
+    /// The value has been clamped just now, to highlight the input.
+    clamped: bool,
+    animate_changes: bool,
+    /// The target value and the task of the animation, the displayed text is in between.
+    animation: Option<(f64, Task<()>)>,
     _subscriptions: Vec<Subscription>,
     _synced_size: bool,
 }
 
 impl NumberInput {
     pub fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
-        // Default pattern for the number input.
-        let pattern = Regex::new(r"^-?(\d+)?\.?(\d+)?$").unwrap();
+        let number_format = NumberFormat::from_locale(&crate::locale());
+        let pattern = number_format.pattern(Notation::Standard);
 
         let input = cx.new(|cx| {
             TextInput::new(window, cx)
@@ -42,13 +77,45 @@ impl NumberInput {
                 .appearance(false)
         });
 
-        let _subscriptions = vec![cx.subscribe(&input, |_, _, event: &InputEvent, cx| {
-            cx.emit(NumberInputEvent::Input(event.clone()));
-        })];
+        let _subscriptions =
+            vec![
+                cx.subscribe_in(&input, window, |this, _, event: &InputEvent, window, cx| {
+                    cx.emit(NumberInputEvent::Input(event.clone()));
+                    match event {
+                        InputEvent::Change(text) => {
+                            if let Some(on_change) = this.on_change.as_ref() {
+                                on_change(text, window, cx);
+                            }
+                        }
+                        InputEvent::Focus => this.finish_animation(window, cx),
+                        InputEvent::Blur => this.clamp_value(window, cx),
+                        InputEvent::Rejected(text) => {
+                            cx.emit(NumberInputEvent::InvalidInput(text.clone()))
+                        }
+                        _ => {}
+                    }
+                }),
+            ];
 
         Self {
             input,
             size: Size::default(),
+            on_change: None,
+            min: None,
+            max: None,
+            step: None,
+            wrap: false,
+            notation: Notation::default(),
+            number_format: None,
+            pattern_format: Some(number_format),
+            use_grouping: false,
+            buttons_layout: ButtonsLayout::default(),
+            wheel_step: false,
+            wheel_delta: px(0.),
+            clamp_feedback: false,
+            clamped: false,
+            animate_changes: false,
+            animation: None,
             _synced_size: false,
             _subscriptions,
         }
@@ -66,6 +133,180 @@ impl NumberInput {
         self
     }
 
+    /// Set a callback to be called when the text of the input is changed.
+    ///
+    /// This is a shortcut of subscribing the [`NumberInputEvent::Input`] with [`InputEvent::Change`].
+    pub fn on_change(
+        mut self,
+        handler: impl Fn(&SharedString, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_change = Some(Box::new(handler));
+        self
+    }
+
+    /// Set the minimum value, the typed value will be clamped on blur.
+    pub fn min(mut self, min: f64) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Set the maximum value, the typed value will be clamped on blur.
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Set the step to apply to the value by the step actions, default is `None`.
+    ///
+    /// By default the [`NumberInputEvent::Step`] only carries the direction, and the subscriber
+    /// decides the magnitude. With a step, the value is stepped and clamped by the input itself
+    /// (emitting the change event), and the `Step` event is still emitted for the notification
+    /// only, the subscriber should not step the value again.
+    pub fn step(mut self, step: f64) -> Self {
+        self.step = Some(step);
+        self
+    }
+
+    /// Returns the step set by [`NumberInput::step`].
+    pub fn step_size(&self) -> Option<f64> {
+        self.step
+    }
+
+    /// Set true to wrap around the `min` and `max` by the `step`, default is `false`.
+    ///
+    /// Stepping past the `max` goes to the `min` and vice versa, e.g. for the hour in `0..=23`.
+    /// This requires both the `min` and `max`, the typed value is still clamped on blur.
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Set the notation to display the value, default is `Notation::Standard`.
+    ///
+    /// The `Scientific` and `Engineering` notation accept the `e` notation on input (e.g. `1.2e-6`),
+    /// and the value will be formatted in the notation on blur, after clamping.
+    ///
+    /// This will replace the pattern of the input.
+    pub fn notation(
+        mut self,
+        notation: Notation,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        self.notation = notation;
+        let number_format = self.current_number_format();
+        self.pattern_format = Some(number_format);
+        self.input.update(cx, |input, cx| {
+            input.set_pattern(number_format.pattern(notation), window, cx)
+        });
+        self
+    }
+
+    /// Set the decimal and grouping separators, default is to follow the current [`locale`](crate::locale).
+    ///
+    /// The input accepts the decimal separator and the grouping separator of the format,
+    /// e.g. `1.234,56` in `de`.
+    pub fn number_format(mut self, number_format: NumberFormat) -> Self {
+        self.number_format = Some(number_format);
+        self
+    }
+
+    /// Set true to display the value with the grouping separator, e.g. `1,234.56`, default is `false`.
+    ///
+    /// This is only for the `Notation::Standard`, the typed text is formatted on blur.
+    pub fn use_grouping(mut self, use_grouping: bool) -> Self {
+        self.use_grouping = use_grouping;
+        self
+    }
+
+    fn current_number_format(&self) -> NumberFormat {
+        self.number_format
+            .unwrap_or_else(|| NumberFormat::from_locale(&crate::locale()))
+    }
+
+    /// Format the value in the notation, with the separators of the number format.
+    fn format_value(&self, value: f64) -> String {
+        let grouping = self.use_grouping && self.notation == Notation::Standard;
+        self.current_number_format()
+            .localize(&self.notation.format(value), grouping)
+    }
+
+    /// Update the pattern for the separators, if the locale or the number format is changed.
+    fn sync_pattern_if_needed(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(pattern_format) = self.pattern_format else {
+            return;
+        };
+        let number_format = self.current_number_format();
+        if number_format != pattern_format {
+            self.pattern_format = Some(number_format);
+            let pattern = number_format.pattern(self.notation);
+            self.input
+                .update(cx, |input, cx| input.set_pattern(pattern, window, cx));
+        }
+    }
+
+    /// Set the layout of the step buttons, default is `ButtonsLayout::Sides`.
+    ///
+    /// The step is still available by the `up` and `down` keys with `ButtonsLayout::Hidden`,
+    /// and by the mouse wheel with [`NumberInput::wheel_step`].
+    pub fn buttons_layout(mut self, layout: ButtonsLayout) -> Self {
+        self.buttons_layout = layout;
+        self
+    }
+
+    /// Set true to step the value by the mouse wheel while the input is focused, default is `false`.
+    ///
+    /// Scrolling up increments the value, and scrolling down decrements it.
+    pub fn wheel_step(mut self, wheel_step: bool) -> Self {
+        self.wheel_step = wheel_step;
+        self
+    }
+
+    /// Set true to animate the digits rolling to the new value set by [`NumberInput::set_value`],
+    /// default is `false`.
+    ///
+    /// The [`NumberInput::value`] returns the new value during the animation,
+    /// and the animation is skipped when the input is focused for editing.
+    pub fn animate_changes(mut self, animate_changes: bool) -> Self {
+        self.animate_changes = animate_changes;
+        self
+    }
+
+    /// Set true to focus the input on the first render, default is `false`.
+    ///
+    /// See [`TextInput::autofocus`].
+    pub fn autofocus(self, autofocus: bool, cx: &mut Context<Self>) -> Self {
+        self.input.update(cx, |input, _| {
+            input.autofocus = autofocus;
+        });
+        self
+    }
+
+    /// Set the tab index to define the focus order independent of the layout, see [`TabStop`].
+    pub fn tab_index(self, tab_index: isize, cx: &mut Context<Self>) -> Self {
+        self.input.update(cx, |input, _| {
+            input.tab_index = Some(tab_index);
+        });
+        self
+    }
+
+    /// Set true to briefly highlight the input when the typed value is clamped by the `min` or `max`,
+    /// default is `false`.
+    ///
+    /// The [`NumberInputEvent::Clamped`] event is emitted either way.
+    pub fn clamp_feedback(mut self, clamp_feedback: bool) -> Self {
+        self.clamp_feedback = clamp_feedback;
+        self
+    }
+
+    /// Set false to keep focus when the Escape key is propagated, default is `true`.
+    pub(crate) fn blur_on_escape(self, blur_on_escape: bool, cx: &mut Context<Self>) -> Self {
+        self.input.update(cx, |input, _| {
+            input.blur_on_escape = blur_on_escape;
+        });
+        self
+    }
+
     pub fn set_size(&mut self, size: Size, window: &mut Window, cx: &mut Context<Self>) {
         self.size = size;
         self.sync_size_to_input_if_needed(window, cx);
@@ -83,17 +324,23 @@ impl NumberInput {
     }
 
     pub fn pattern(
-        self,
+        mut self,
         pattern: regex::Regex,
         window: &mut Window,
         cx: &mut Context<Self>,
     ) -> Self {
+        // Keep the custom pattern when the locale changes.
+        self.pattern_format = None;
         self.input
             .update(cx, |input, cx| input.set_pattern(pattern, window, cx));
         self
     }
 
-    pub fn set_value(
+    /// Set the text of the input as it is, this will emit the change event.
+    ///
+    /// This was named `set_value` before, use [`NumberInput::set_value`] to set the number
+    /// without the change event.
+    pub fn set_text(
         &self,
         text: impl Into<SharedString>,
         window: &mut Window,
@@ -103,6 +350,90 @@ impl NumberInput {
             .update(cx, |input, cx| input.set_text(text, window, cx))
     }
 
+    /// Get the value of the input, returns `None` if the text is empty or not a number.
+    pub fn value(&self, cx: &App) -> Option<f64> {
+        if let Some((target, _)) = self.animation.as_ref() {
+            return Some(*target);
+        }
+
+        self.current_number_format()
+            .parse(self.input.read(cx).text())
+    }
+
+    /// Set the value of the input, `None` to clear.
+    ///
+    /// The value is clamped into the `min` and `max`, and formatted in the notation.
+    /// This will not emit the change event.
+    pub fn set_value(&mut self, value: Option<f64>, window: &mut Window, cx: &mut Context<Self>) {
+        let from = self.value(cx);
+        self.animation = None;
+
+        let Some(value) = value.map(|value| clamp_between(value, self.min, self.max)) else {
+            return self.set_text_silently("", window, cx);
+        };
+        let text = self.format_value(value);
+
+        let focused = self.input.focus_handle(cx).is_focused(window);
+        let animate = self.animate_changes && !focused && self.notation == Notation::Standard;
+        let Some(from) = from.filter(|from| animate && from.is_finite() && *from != value) else {
+            return self.set_text_silently(text, window, cx);
+        };
+
+        // Roll the digits in the decimals of the new value.
+        let number_format = self.current_number_format();
+        let grouping = self.use_grouping;
+        let decimals = text
+            .split_once(number_format.decimal)
+            .map_or(0, |(_, fraction)| fraction.len());
+        let task = cx.spawn_in(window, async move |this, cx| {
+            for frame in 1..ANIMATION_FRAMES {
+                Timer::after(ANIMATION_DURATION / ANIMATION_FRAMES).await;
+
+                // Ease out cubic.
+                let t = 1. - (1. - frame as f64 / ANIMATION_FRAMES as f64).powi(3);
+                let text = format!("{:.*}", decimals, from + (value - from) * t);
+                let text = number_format.localize(&text, grouping);
+                let result = this.update_in(cx, |this, window, cx| {
+                    this.set_text_silently(text, window, cx)
+                });
+                if result.is_err() {
+                    return;
+                }
+            }
+
+            Timer::after(ANIMATION_DURATION / ANIMATION_FRAMES).await;
+            _ = this.update_in(cx, |this, window, cx| this.finish_animation(window, cx));
+        });
+        self.animation = Some((value, task));
+    }
+
+    /// Stop the animation and display the target value.
+    fn finish_animation(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some((target, _)) = self.animation.take() {
+            self.set_text_silently(self.format_value(target), window, cx);
+        }
+    }
+
+    /// Set the text without emitting the change event.
+    fn set_text_silently(
+        &self,
+        text: impl Into<SharedString>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.input.update(cx, |input, cx| {
+            input.silent = true;
+            input.set_text(text, window, cx);
+            input.silent = false;
+        })
+    }
+
+    /// Set the minimum and maximum value, used by the bound [`Slider`](crate::slider::Slider).
+    pub(crate) fn set_bounds(&mut self, min: Option<f64>, max: Option<f64>) {
+        self.min = min;
+        self.max = max;
+    }
+
     pub fn set_disabled(&self, disabled: bool, window: &mut Window, cx: &mut Context<Self>) {
         self.input
             .update(cx, |input, cx| input.set_disabled(disabled, window, cx));
@@ -116,6 +447,13 @@ impl NumberInput {
         self.on_action_decrement(&Decrement, window, cx);
     }
 
+    /// Dispatch a step action programmatically, e.g. from your own buttons or shortcuts.
+    ///
+    /// This will emit the same [`NumberInputEvent::Step`] as the built-in buttons.
+    pub fn step_by(&mut self, action: StepAction, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_step(action, window, cx);
+    }
+
     fn on_action_increment(&mut self, _: &Increment, window: &mut Window, cx: &mut Context<Self>) {
         self.on_step(StepAction::Increment, window, cx);
     }
@@ -124,14 +462,82 @@ impl NumberInput {
         self.on_step(StepAction::Decrement, window, cx);
     }
 
-    fn on_step(&mut self, action: StepAction, _: &mut Window, cx: &mut Context<Self>) {
+    fn on_scroll_wheel(
+        &mut self,
+        event: &ScrollWheelEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if !self.wheel_step || !self.input.focus_handle(cx).is_focused(window) {
+            return;
+        }
+        // Keep the parent from scrolling while stepping the value.
+        cx.stop_propagation();
+
+        self.wheel_delta += event.delta.pixel_delta(WHEEL_STEP_DELTA).y;
+        while self.wheel_delta.abs() >= WHEEL_STEP_DELTA {
+            if self.wheel_delta > px(0.) {
+                self.wheel_delta -= WHEEL_STEP_DELTA;
+                self.on_step(StepAction::Increment, window, cx);
+            } else {
+                self.wheel_delta += WHEEL_STEP_DELTA;
+                self.on_step(StepAction::Decrement, window, cx);
+            }
+        }
+    }
+
+    fn on_step(&mut self, action: StepAction, window: &mut Window, cx: &mut Context<Self>) {
         if self.input.read(cx).disabled {
             return;
         }
 
+        if let Some(step) = self.step {
+            let value = self.value(cx).unwrap_or(self.min.unwrap_or(0.));
+            let value = step_value(value, step, action, self.min, self.max, self.wrap);
+            self.animation = None;
+            self.set_text(self.format_value(value), window, cx);
+        }
+
         cx.emit(NumberInputEvent::Step(action));
     }
 
+    /// Clamp the typed value into the `min` and `max`, and format it in the notation.
+    fn clamp_value(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let text = self.input.read(cx).text().clone();
+        let Some(entered) = self.current_number_format().parse(&text) else {
+            return;
+        };
+
+        let applied = clamp_between(entered, self.min, self.max);
+        // Keep the typed text as it is in the standard notation, if it is not clamped.
+        let clamped = applied != entered;
+        if clamped || self.notation != Notation::Standard || self.use_grouping {
+            let formatted = self.format_value(applied);
+            if formatted != text.as_ref() {
+                self.set_text(formatted, window, cx);
+            }
+        }
+        if !clamped {
+            return;
+        }
+
+        cx.emit(NumberInputEvent::Clamped { entered, applied });
+
+        if self.clamp_feedback {
+            self.clamped = true;
+            cx.notify();
+
+            cx.spawn(async move |this, cx| {
+                Timer::after(CLAMP_FEEDBACK_DURATION).await;
+                _ = this.update(cx, |this, cx| {
+                    this.clamped = false;
+                    cx.notify();
+                });
+            })
+            .detach();
+        }
+    }
+
     fn sync_size_to_input_if_needed(&mut self, window: &mut Window, cx: &mut Context<Self>) {
         if !self._synced_size {
             self.input
@@ -141,21 +547,274 @@ impl NumberInput {
     }
 }
 
+impl Validatable for NumberInput {
+    fn validate_field(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Result<(), SharedString> {
+        self.input
+            .update(cx, |input, cx| input.validate_field(window, cx))
+    }
+}
+
 impl Focusable for NumberInput {
     fn focus_handle(&self, cx: &App) -> FocusHandle {
         self.input.focus_handle(cx)
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+impl TabStop for NumberInput {
+    fn tab_stop_index(&self, cx: &App) -> Option<isize> {
+        self.input.read(cx).tab_index
+    }
+}
+
+/// Clamp the value into the `min` and `max`, the `min` wins if `min` > `max`.
+pub(crate) fn clamp_between(value: f64, min: Option<f64>, max: Option<f64>) -> f64 {
+    let mut value = value;
+    if let Some(max) = max {
+        value = value.min(max);
+    }
+    if let Some(min) = min {
+        value = value.max(min);
+    }
+    value
+}
+
+/// Step the value by the `step`, and wrap or clamp it into the `min` and `max`.
+fn step_value(
+    value: f64,
+    step: f64,
+    action: StepAction,
+    min: Option<f64>,
+    max: Option<f64>,
+    wrap: bool,
+) -> f64 {
+    let value = match action {
+        StepAction::Increment => value + step,
+        StepAction::Decrement => value - step,
+    };
+    let value = round_to_decimals_of(value, step);
+    match (wrap, min, max) {
+        (true, Some(min), Some(max)) => wrap_between(value, min, max),
+        _ => clamp_between(value, min, max),
+    }
+}
+
+/// Returns the delta for the subscriber of the [`NumberInputEvent::Step`] to apply, e.g. a bound
+/// `Slider`, or `None` if the input has its own `step` that is already applied and emitted
+/// as the change event.
+pub(crate) fn subscriber_step(
+    input_step: Option<f64>,
+    step: f64,
+    action: StepAction,
+) -> Option<f64> {
+    if input_step.is_some() {
+        return None;
+    }
+
+    Some(match action {
+        StepAction::Increment => step,
+        StepAction::Decrement => -step,
+    })
+}
+
+/// Wrap the value stepped past the `max` to the `min`, and past the `min` to the `max`.
+fn wrap_between(value: f64, min: f64, max: f64) -> f64 {
+    if min > max {
+        min
+    } else if value > max {
+        min
+    } else if value < min {
+        max
+    } else {
+        value
+    }
+}
+
+/// Round the value to the nearest multiple of the step,
+/// and to the decimals of the step to avoid the float error, e.g. `0.30000000000000004`.
+pub(crate) fn round_to_step(value: f64, step: f64) -> f64 {
+    if step <= 0. || !step.is_finite() {
+        return value;
+    }
+
+    round_to_decimals_of((value / step).round() * step, step)
+}
+
+/// Round the value to the decimals of the step, to avoid the float error.
+fn round_to_decimals_of(value: f64, step: f64) -> f64 {
+    let decimals = step
+        .to_string()
+        .split_once('.')
+        .map_or(0, |(_, fraction)| fraction.len());
+    format!("{:.*}", decimals, value).parse().unwrap_or(value)
+}
+
+/// The notation to display the value of the [`NumberInput`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Notation {
+    /// Display the value in decimal, e.g. `0.0000012`, this is the default.
+    #[default]
+    Standard,
+    /// Display the value with one digit before the decimal point, e.g. `1.2e-6`.
+    Scientific,
+    /// Display the value with the exponent in multiple of 3, e.g. `470e-9`.
+    Engineering,
+}
+
+impl Notation {
+    /// Format the value in the notation.
+    pub fn format(&self, value: f64) -> String {
+        match self {
+            Self::Standard => value.to_string(),
+            Self::Scientific => format!("{:e}", value),
+            Self::Engineering => {
+                if value == 0. || !value.is_finite() {
+                    return format!("{:e}", value);
+                }
+
+                let exp = (value.abs().log10() / 3.).floor() as i32 * 3;
+                // Round the mantissa to avoid the float error, e.g. `1.2000000000000002`.
+                let mantissa = format!("{:.9}", value / 10f64.powi(exp));
+                let mantissa = mantissa.trim_end_matches('0').trim_end_matches('.');
+                format!("{}e{}", mantissa, exp)
+            }
+        }
+    }
+}
+
+/// The decimal and grouping separators to parse and display the value of the [`NumberInput`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberFormat {
+    /// The decimal separator, e.g. `.` in `en`, `,` in `de`.
+    pub decimal: char,
+    /// The grouping separator of the thousands, e.g. `,` in `en`, `.` in `de`.
+    pub group: char,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self {
+            decimal: '.',
+            group: ',',
+        }
+    }
+}
+
+impl NumberFormat {
+    /// Returns the number format of the locale, e.g.: `de`, `it` use the decimal comma.
+    pub fn from_locale(locale: &str) -> Self {
+        let lang = locale.split(['-', '_']).next().unwrap_or_default();
+        match lang.to_ascii_lowercase().as_str() {
+            "de" | "it" | "es" | "pt" | "nl" | "id" | "tr" | "da" | "el" | "ro" | "hr" | "sl"
+            | "sr" | "vi" => Self {
+                decimal: ',',
+                group: '.',
+            },
+            "fr" | "ru" | "pl" | "sv" | "fi" | "nb" | "no" | "cs" | "sk" | "uk" | "hu" | "bg"
+            | "lt" | "lv" | "et" => Self {
+                decimal: ',',
+                group: ' ',
+            },
+            _ => Self::default(),
+        }
+    }
+
+    /// Parse the text with the separators, the grouping separators are ignored.
+    pub(crate) fn parse(&self, text: &str) -> Option<f64> {
+        let text = text
+            .chars()
+            .filter(|c| *c != self.group)
+            .map(|c| if c == self.decimal { '.' } else { c })
+            .collect::<String>();
+        text.parse().ok()
+    }
+
+    /// Replace the decimal point of the formatted text, and group the integer digits if `grouping`.
+    pub(crate) fn localize(&self, text: &str, grouping: bool) -> String {
+        let (integer, fraction) = match text.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (integer_part(text), None),
+        };
+        let rest = &text[integer.len() + fraction.map_or(0, |fraction| fraction.len() + 1)..];
+
+        let mut result = String::with_capacity(text.len() + 4);
+        let digits = integer.trim_start_matches('-');
+        result.push_str(&integer[..integer.len() - digits.len()]);
+        for (ix, c) in digits.chars().enumerate() {
+            if grouping && ix > 0 && (digits.len() - ix) % 3 == 0 {
+                result.push(self.group);
+            }
+            result.push(c);
+        }
+        if let Some(fraction) = fraction {
+            result.push(self.decimal);
+            result.push_str(fraction);
+        }
+        result.push_str(rest);
+        result
+    }
+
+    /// The pattern to accept the typed text in the notation.
+    fn pattern(&self, notation: Notation) -> Regex {
+        let decimal = regex::escape(&self.decimal.to_string());
+        let group = regex::escape(&self.group.to_string());
+        let pattern = match notation {
+            Notation::Standard => format!(r"^-?[\d{}]*{}?(\d+)?$", group, decimal),
+            Notation::Scientific | Notation::Engineering => {
+                format!(r"^-?(\d+)?{}?(\d+)?([eE][-+]?(\d+)?)?$", decimal)
+            }
+        };
+        Regex::new(&pattern).unwrap()
+    }
+}
+
+/// Returns the leading integer part of the text, e.g. `-12` in `-12e3`.
+fn integer_part(text: &str) -> &str {
+    let end = text
+        .char_indices()
+        .find(|(ix, c)| !(c.is_ascii_digit() || (*ix == 0 && *c == '-')))
+        .map_or(text.len(), |(ix, _)| ix);
+    &text[..end]
+}
+
+/// The layout of the step buttons of the [`NumberInput`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ButtonsLayout {
+    /// The `-` button on the left and the `+` button on the right, this is the default.
+    #[default]
+    Sides,
+    /// The up and down buttons stacked on the right.
+    Stacked,
+    /// No buttons, step by the keys, or the mouse wheel with [`NumberInput::wheel_step`].
+    Hidden,
+}
+
+/// The direction of a step in the [`NumberInput`].
+///
+/// The step only carries the direction, the magnitude of the step is decided by the
+/// subscriber of the [`NumberInputEvent::Step`] event, unless the input has its own
+/// [`NumberInput::step`], then the input is the only one to step the value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum StepAction {
+    /// Step down, e.g. the `-` button or the `down` key.
     Decrement,
+    /// Step up, e.g. the `+` button or the `up` key.
     Increment,
 }
 
 pub enum NumberInputEvent {
+    /// The event from the inner text input.
     Input(InputEvent),
+    /// The step action is triggered by the buttons, keys or [`NumberInput::step_by`].
     Step(StepAction),
+    /// The typed value is clamped by the `min` or `max` on blur.
+    Clamped { entered: f64, applied: f64 },
+    /// The typed or pasted text is rejected as not a number or by the `pattern`,
+    /// the text of the input is kept as before.
+    InvalidInput(SharedString),
 }
 
 impl EventEmitter<NumberInputEvent> for NumberInput {}
@@ -171,20 +830,32 @@ impl Render for NumberInput {
 
         // Sync size to input at first.
         self.sync_size_to_input_if_needed(window, cx);
+        self.sync_pattern_if_needed(window, cx);
         let btn_size = match self.size {
             Size::XSmall | Size::Small => Size::Size(px(16.)),
-            _ => Size::XSmall,
+            Size::Medium | Size::Large | Size::Size(_) => Size::XSmall,
+            Size::XLarge => Size::Small,
+        };
+        // The stacked buttons are in half of the input height.
+        let stacked_btn_size = match self.size {
+            Size::XSmall | Size::Small => Size::Size(px(11.)),
+            Size::Large => Size::Size(px(18.)),
+            Size::XLarge => Size::Size(px(20.)),
+            Size::Medium | Size::Size(_) => Size::Size(px(14.)),
         };
+        let layout = self.buttons_layout;
 
         h_flex()
             .key_context(KEY_CONTENT)
             .on_action(cx.listener(Self::on_action_increment))
             .on_action(cx.listener(Self::on_action_decrement))
+            .on_scroll_wheel(cx.listener(Self::on_scroll_wheel))
             .flex_1()
-            .input_size(self.size)
+            .input_size_with_density(self.size, cx.theme().density)
             .px(match self.size {
                 Size::XSmall => px(1.),
                 Size::Small => px(2.),
+                Size::XLarge => px(4.),
                 _ => px(3.),
             })
             .bg(cx.theme().background)
@@ -192,24 +863,148 @@ impl Render for NumberInput {
             .border_1()
             .rounded(cx.theme().radius)
             .when(focused, |this| this.focused_border(cx))
-            .child(
-                Button::new("minus")
-                    .ghost()
-                    .with_size(btn_size)
-                    .icon(IconName::Minus)
-                    .on_click(cx.listener(|this, _, window, cx| {
-                        this.on_step(StepAction::Decrement, window, cx)
-                    })),
-            )
+            .when(self.clamped, |this| this.border_color(cx.theme().danger))
+            .when(layout == ButtonsLayout::Sides, |this| {
+                this.child(
+                    Button::new("minus")
+                        .ghost()
+                        .with_size(btn_size)
+                        .icon(IconName::Minus)
+                        .on_click(cx.listener(|this, _, window, cx| {
+                            this.on_step(StepAction::Decrement, window, cx)
+                        })),
+                )
+            })
             .child(self.input.clone())
-            .child(
-                Button::new("plus")
-                    .ghost()
-                    .with_size(btn_size)
-                    .icon(IconName::Plus)
-                    .on_click(cx.listener(|this, _, window, cx| {
-                        this.on_step(StepAction::Increment, window, cx)
-                    })),
-            )
+            .when(layout == ButtonsLayout::Sides, |this| {
+                this.child(
+                    Button::new("plus")
+                        .ghost()
+                        .with_size(btn_size)
+                        .icon(IconName::Plus)
+                        .on_click(cx.listener(|this, _, window, cx| {
+                            this.on_step(StepAction::Increment, window, cx)
+                        })),
+                )
+            })
+            .when(layout == ButtonsLayout::Stacked, |this| {
+                this.child(
+                    v_flex()
+                        .child(
+                            Button::new("plus")
+                                .ghost()
+                                .with_size(stacked_btn_size)
+                                .icon(IconName::ChevronUp)
+                                .on_click(cx.listener(|this, _, window, cx| {
+                                    this.on_step(StepAction::Increment, window, cx)
+                                })),
+                        )
+                        .child(
+                            Button::new("minus")
+                                .ghost()
+                                .with_size(stacked_btn_size)
+                                .icon(IconName::ChevronDown)
+                                .on_click(cx.listener(|this, _, window, cx| {
+                                    this.on_step(StepAction::Decrement, window, cx)
+                                })),
+                        ),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        clamp_between, round_to_decimals_of, round_to_step, step_value, subscriber_step,
+        wrap_between, Notation, NumberFormat, StepAction,
+    };
+
+    #[test]
+    fn test_notation_format() {
+        assert_eq!(Notation::Standard.format(1.5), "1.5");
+        assert_eq!(Notation::Standard.format(1.2e-6), "0.0000012");
+
+        assert_eq!(Notation::Scientific.format(1.2e-6), "1.2e-6");
+        assert_eq!(Notation::Scientific.format(12345.), "1.2345e4");
+        assert_eq!(Notation::Scientific.format(0.), "0e0");
+
+        assert_eq!(Notation::Engineering.format(1.2e-6), "1.2e-6");
+        assert_eq!(Notation::Engineering.format(12345.), "12.345e3");
+        assert_eq!(Notation::Engineering.format(-0.00047), "-470e-6");
+        assert_eq!(Notation::Engineering.format(1000.), "1e3");
+        assert_eq!(Notation::Engineering.format(0.), "0e0");
+    }
+
+    #[test]
+    fn test_clamp_and_round_to_step() {
+        assert_eq!(clamp_between(5., Some(0.), Some(10.)), 5.);
+        assert_eq!(clamp_between(-1., Some(0.), Some(10.)), 0.);
+        assert_eq!(clamp_between(11., None, Some(10.)), 10.);
+        assert_eq!(clamp_between(11., None, None), 11.);
+
+        assert_eq!(round_to_step(0.1 + 0.2, 0.1), 0.3);
+        assert_eq!(round_to_step(7.4, 5.), 5.);
+        assert_eq!(round_to_step(7.6, 5.), 10.);
+        assert_eq!(round_to_step(1.26, 0.25), 1.25);
+        assert_eq!(round_to_step(1.26, 0.), 1.26);
+    }
+
+    #[test]
+    fn test_wrap_between() {
+        assert_eq!(wrap_between(24., 0., 23.), 0.);
+        assert_eq!(wrap_between(-1., 0., 23.), 23.);
+        assert_eq!(wrap_between(12., 0., 23.), 12.);
+        assert_eq!(wrap_between(5., 10., 0.), 10.);
+
+        assert_eq!(round_to_decimals_of(0.1 + 0.2, 0.1), 0.3);
+        assert_eq!(round_to_decimals_of(1. + 2., 2.), 3.);
+    }
+
+    #[test]
+    fn test_bound_step() {
+        let inc = StepAction::Increment;
+        let dec = StepAction::Decrement;
+        assert_eq!(step_value(10., 1., inc, Some(0.), Some(10.), true), 0.);
+        assert_eq!(step_value(10., 1., inc, Some(0.), Some(10.), false), 10.);
+        assert_eq!(step_value(0.2, 0.1, inc, None, None, false), 0.3);
+
+        // Bound to a slider with its own step: the input steps once by the change event,
+        // and the slider must not step again on the `Step` event, even across the wrap.
+        let value = step_value(10., 1., inc, Some(0.), Some(10.), true);
+        assert_eq!(subscriber_step(Some(1.), 1., inc), None);
+        assert_eq!(value, 0.);
+
+        // Without its own step, the slider decides the step.
+        assert_eq!(subscriber_step(None, 0.5, inc), Some(0.5));
+        assert_eq!(subscriber_step(None, 0.5, dec), Some(-0.5));
+    }
+
+    #[test]
+    fn test_number_format() {
+        let en = NumberFormat::from_locale("en");
+        let de = NumberFormat::from_locale("de-DE");
+        let fr = NumberFormat::from_locale("fr");
+        assert_eq!(en, NumberFormat::default());
+        assert_eq!(de.decimal, ',');
+        assert_eq!(fr.group, ' ');
+
+        assert_eq!(en.parse("1,234.56"), Some(1234.56));
+        assert_eq!(de.parse("1.234,56"), Some(1234.56));
+        assert_eq!(fr.parse("-1 234,5"), Some(-1234.5));
+        assert_eq!(de.parse("1,2e3"), Some(1200.));
+        assert_eq!(de.parse(""), None);
+
+        assert_eq!(en.localize("1234.56", true), "1,234.56");
+        assert_eq!(de.localize("1234.56", true), "1.234,56");
+        assert_eq!(de.localize("-1234567", true), "-1.234.567");
+        assert_eq!(de.localize("123", true), "123");
+        assert_eq!(de.localize("1234.5", false), "1234,5");
+        assert_eq!(de.localize("1.2e-6", false), "1,2e-6");
+        assert_eq!(de.localize("12e3", false), "12e3");
+
+        assert!(de.pattern(Notation::Standard).is_match("-1.234,5"));
+        assert!(!de.pattern(Notation::Standard).is_match("1,2,3"));
+        assert!(de.pattern(Notation::Scientific).is_match("1,2e-6"));
     }
 }
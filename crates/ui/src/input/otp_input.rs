@@ -1,10 +1,17 @@
+use std::time::Duration;
+
 use gpui::{
     div, prelude::FluentBuilder, px, AnyElement, AppContext as _, Context, Entity, EventEmitter,
     FocusHandle, Focusable, InteractiveElement, IntoElement, KeyDownEvent, MouseButton,
-    MouseDownEvent, ParentElement as _, Render, SharedString, Styled as _, Subscription, Window,
+    MouseDownEvent, ParentElement as _, Render, SharedString, Styled as _, Subscription, Task,
+    Timer, Window,
 };
 
-use crate::{h_flex, v_flex, ActiveTheme, Icon, IconName, Sizable, Size};
+use rust_i18n::t;
+
+use crate::{
+    form::Validatable, h_flex, v_flex, ActiveTheme, Icon, IconName, Sizable, Size, StyledExt as _,
+};
 
 use super::{blink_cursor::BlinkCursor, InputEvent};
 
@@ -26,6 +33,13 @@ pub struct OtpInput {
     length: usize,
     number_of_groups: usize,
     masked: bool,
+    reveal_duration: Option<Duration>,
+    /// The index of the just typed cell that is revealed while masked, and the task to mask it.
+    revealed: Option<(usize, Task<()>)>,
+    /// The leading digits that are known, rendered as read-only cells.
+    fixed_prefix: SharedString,
+    /// The element to focus after the last cell is filled.
+    complete_focus: Option<FocusHandle>,
     value: SharedString,
     blink_cursor: Entity<BlinkCursor>,
     size: Size,
@@ -60,7 +74,11 @@ impl OtpInput {
             length,
             number_of_groups: 2,
             value: SharedString::default(),
+            fixed_prefix: SharedString::default(),
+            complete_focus: None,
             masked: false,
+            reveal_duration: None,
+            revealed: None,
             blink_cursor: blink_cursor.clone(),
             size: Size::Medium,
             _subscriptions,
@@ -75,10 +93,40 @@ impl OtpInput {
 
     /// Set default value of the OTP Input.
     pub fn default_value(mut self, value: impl Into<SharedString>) -> Self {
-        self.value = value.into();
+        self.value = self.with_fixed_prefix(value.into());
+        self
+    }
+
+    /// Set the leading digits that are known (e.g. a country-specific prefix), default is empty.
+    ///
+    /// The prefix is rendered as filled and read-only cells, the typing starts at the first
+    /// editable cell and the backspace can't delete into the prefix.
+    /// The value and the change event include the prefix.
+    pub fn fixed_prefix(mut self, prefix: &str) -> Self {
+        self.fixed_prefix = prefix
+            .chars()
+            .filter(|c| c.is_ascii_digit())
+            .take(self.length)
+            .collect::<String>()
+            .into();
+        self.value = self.with_fixed_prefix(self.value.clone());
         self
     }
 
+    /// Returns the value starting with the fixed prefix, the value is kept if it already does.
+    fn with_fixed_prefix(&self, value: SharedString) -> SharedString {
+        if value.starts_with(self.fixed_prefix.as_ref()) {
+            return value;
+        }
+
+        self.fixed_prefix
+            .chars()
+            .chain(value.chars())
+            .take(self.length)
+            .collect::<String>()
+            .into()
+    }
+
     /// Set value of the OTP Input.
     pub fn set_value(
         &mut self,
@@ -86,7 +134,7 @@ impl OtpInput {
         _: &mut Window,
         cx: &mut Context<Self>,
     ) {
-        self.value = value.into();
+        self.value = self.with_fixed_prefix(value.into());
         cx.notify();
     }
 
@@ -101,6 +149,23 @@ impl OtpInput {
         self
     }
 
+    /// Set the duration to show the just typed digit before masking it, default is `None`.
+    ///
+    /// This is only for the `masked` input, `None` to mask the digit immediately.
+    pub fn reveal_duration(mut self, duration: Option<Duration>) -> Self {
+        self.reveal_duration = duration;
+        self
+    }
+
+    /// Set the element to move the focus to after the last cell is filled by typing,
+    /// e.g. the next `OtpInput` or the verify button.
+    ///
+    /// The focus is moved after the [`InputEvent::Change`] is emitted.
+    pub fn on_complete_focus(mut self, focus_handle: FocusHandle) -> Self {
+        self.complete_focus = Some(focus_handle);
+        self
+    }
+
     /// Set masked to true use masked input.
     pub fn set_masked(&mut self, masked: bool, _: &mut Window, cx: &mut Context<Self>) {
         self.masked = masked;
@@ -111,6 +176,35 @@ impl OtpInput {
         self.focus_handle.focus(window);
     }
 
+    /// Focus the cell at the index (clamped to the length) to type, e.g. to fix a wrong digit.
+    ///
+    /// The cells are filled in order, so the digits from the cell are cleared to retype,
+    /// and the [`InputEvent::Change`] is emitted with the remaining digits.
+    /// This does nothing if the cell is in the `fixed_prefix`.
+    pub fn focus_cell(&mut self, ix: usize, window: &mut Window, cx: &mut Context<Self>) {
+        let ix = ix.min(self.length.saturating_sub(1));
+        if ix < self.fixed_prefix.chars().count() {
+            return;
+        }
+
+        if ix < self.value.chars().count() {
+            self.value = self.value.chars().take(ix).collect::<String>().into();
+            self.revealed = None;
+            cx.emit(InputEvent::Change(self.value.clone()));
+        }
+        self.focus_handle.focus(window);
+        cx.notify();
+    }
+
+    /// Focus the first empty cell, or only the input if all the cells are filled.
+    ///
+    /// The entered digits are kept, e.g. to continue typing after the resend,
+    /// use [`OtpInput::focus_cell`] to retype from a cell.
+    pub fn focus_first_empty(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.focus_handle.focus(window);
+        cx.notify();
+    }
+
     fn on_input_mouse_down(
         &mut self,
         _: &MouseDownEvent,
@@ -125,10 +219,11 @@ impl OtpInput {
         let ix = chars.len();
 
         let key = event.keystroke.key.as_str();
+        let is_backspace = key == "backspace";
 
         match key {
             "backspace" => {
-                if ix > 0 {
+                if ix > self.fixed_prefix.chars().count() {
                     let ix = ix - 1;
                     chars.remove(ix);
                 }
@@ -146,6 +241,7 @@ impl OtpInput {
                 }
 
                 chars.push(c);
+                self.reveal(ix, cx);
 
                 window.prevent_default();
                 cx.stop_propagation();
@@ -157,6 +253,9 @@ impl OtpInput {
 
         if self.value.chars().count() == self.length {
             cx.emit(InputEvent::Change(self.value.clone()));
+            if let (false, Some(focus_handle)) = (is_backspace, self.complete_focus.as_ref()) {
+                focus_handle.focus(window);
+            }
         }
         cx.notify()
     }
@@ -175,6 +274,23 @@ impl OtpInput {
         cx.emit(InputEvent::Blur);
     }
 
+    /// Show the typed digit of the cell for the `reveal_duration`, the previous revealed one is masked.
+    fn reveal(&mut self, ix: usize, cx: &mut Context<Self>) {
+        self.revealed = None;
+        let Some(duration) = self.reveal_duration.filter(|_| self.masked) else {
+            return;
+        };
+
+        let task = cx.spawn(async move |this, cx| {
+            Timer::after(duration).await;
+            _ = this.update(cx, |this, cx| {
+                this.revealed = None;
+                cx.notify();
+            });
+        });
+        self.revealed = Some((ix, task));
+    }
+
     fn pause_blink_cursor(&mut self, cx: &mut Context<Self>) {
         self.blink_cursor.update(cx, |cursor, cx| {
             cursor.pause(cx);
@@ -189,6 +305,21 @@ impl Sizable for OtpInput {
     }
 }
 
+impl Validatable for OtpInput {
+    /// The value is invalid if not all the cells are filled.
+    fn validate_field(
+        &mut self,
+        _: &mut Window,
+        _: &mut Context<Self>,
+    ) -> Result<(), SharedString> {
+        if self.value.chars().count() < self.length {
+            return Err(t!("Form.incomplete").into());
+        }
+
+        Ok(())
+    }
+}
+
 impl Focusable for OtpInput {
     fn focus_handle(&self, _: &gpui::App) -> FocusHandle {
         self.focus_handle.clone()
@@ -206,12 +337,14 @@ impl Render for OtpInput {
             Size::Small => px(14.),
             Size::Medium => px(16.),
             Size::Large => px(18.),
+            Size::XLarge => px(22.),
             Size::Size(v) => v * 0.5,
         };
 
         let mut groups: Vec<Vec<AnyElement>> = Vec::with_capacity(self.number_of_groups);
         let mut group_ix = 0;
         let group_items_count = self.length / self.number_of_groups;
+        let fixed_count = self.fixed_prefix.chars().count();
         for _ in 0..self.number_of_groups {
             groups.push(vec![]);
         }
@@ -223,6 +356,8 @@ impl Render for OtpInput {
             }
 
             let is_input_focused = i == self.value.chars().count() && is_focused;
+            let is_fixed = i < fixed_count;
+            let is_revealed = self.revealed.as_ref().map_or(false, |(ix, _)| *ix == i);
 
             groups[group_ix].push(
                 h_flex()
@@ -230,7 +365,11 @@ impl Render for OtpInput {
                     .border_1()
                     .border_color(cx.theme().input)
                     .bg(cx.theme().background)
-                    .when(is_input_focused, |this| this.border_color(cx.theme().ring))
+                    .when(is_fixed, |this| {
+                        this.bg(cx.theme().muted)
+                            .text_color(cx.theme().muted_foreground)
+                    })
+                    .when(is_input_focused, |this| this.focused_border(cx))
                     .when(cx.theme().shadow, |this| this.shadow_sm())
                     .items_center()
                     .justify_center()
@@ -241,12 +380,13 @@ impl Render for OtpInput {
                         Size::Small => this.w_6().h_6(),
                         Size::Medium => this.w_8().h_8(),
                         Size::Large => this.w_11().h_11(),
+                        Size::XLarge => this.w(px(56.)).h(px(56.)),
                         Size::Size(px) => this.w(px).h(px),
                     })
                     .on_mouse_down(MouseButton::Left, cx.listener(Self::on_input_mouse_down))
                     .map(|this| match c {
                         Some(c) => {
-                            if self.masked {
+                            if self.masked && !is_fixed && !is_revealed {
                                 this.child(
                                     Icon::new(IconName::Asterisk)
                                         .text_color(cx.theme().secondary_foreground)
@@ -1,4 +1,5 @@
 mod colors;
+mod direction;
 mod event;
 mod focusable;
 mod icon;
@@ -22,6 +23,7 @@ pub mod button;
 pub mod checkbox;
 pub mod clipboard;
 pub mod color_picker;
+pub mod combobox;
 pub mod context_menu;
 pub mod description_list;
 pub mod divider;
@@ -63,8 +65,9 @@ use gpui::App;
 pub use wry;
 
 pub use crate::Disableable;
+pub use direction::*;
 pub use event::InteractiveElementExt;
-pub use focusable::FocusableCycle;
+pub use focusable::{FocusableCycle, TabStop};
 pub use root::{ContextModal, Root};
 pub use styled::*;
 pub use time::*;
@@ -88,6 +91,8 @@ rust_i18n::i18n!("locales", fallback = "en");
 /// You can initialize the UI module at your application's entry point.
 pub fn init(cx: &mut App) {
     theme::init(cx);
+    calendar::init(cx);
+    combobox::init(cx);
     date_picker::init(cx);
     dock::init(cx);
     drawer::init(cx);
@@ -98,6 +103,7 @@ pub fn init(cx: &mut App) {
     popover::init(cx);
     popup_menu::init(cx);
     table::init(cx);
+    time_picker::init(cx);
 }
 
 #[inline]
@@ -105,11 +111,28 @@ pub fn locale() -> impl Deref<Target = str> {
     rust_i18n::locale()
 }
 
+/// Set the locale of the UI components.
+///
+/// This only changes the locale, the opened views will use the new locale at the next render,
+/// use [`change_locale`] to refresh them immediately.
 #[inline]
 pub fn set_locale(locale: &str) {
     rust_i18n::set_locale(locale)
 }
 
+/// Change the locale of the UI components and refresh all windows.
+///
+/// The components (e.g. `DatePicker` placeholder, `Calendar` weekdays and months) read the
+/// translations at render time, so refreshing the windows is enough to apply the new locale.
+///
+/// ```ignore
+/// ui::change_locale("zh-CN", cx);
+/// ```
+pub fn change_locale(locale: &str, cx: &mut App) {
+    set_locale(locale);
+    cx.refresh_windows();
+}
+
 #[inline]
 pub(crate) fn measure_enable() -> bool {
     std::env::var("ZED_MEASUREMENTS").is_ok()
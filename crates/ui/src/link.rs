@@ -3,7 +3,7 @@ use gpui::{
     RenderOnce, SharedString, Stateful, StatefulInteractiveElement, Styled,
 };
 
-use crate::ActiveTheme as _;
+use crate::{ActiveTheme as _, StyledExt as _};
 
 /// A Link element like a `<a>` tag in HTML.
 #[derive(IntoElement)]
@@ -60,21 +60,24 @@ impl RenderOnce for Link {
         let href = self.href.clone();
         let on_click = self.on_click;
 
+        if self.disabled {
+            return div()
+                .text_color(cx.theme().link)
+                .text_decoration_1()
+                .text_decoration_color(cx.theme().link)
+                .disabled_style(cx)
+                .child(self.base);
+        }
+
         div()
             .text_color(cx.theme().link)
             .text_decoration_1()
             .text_decoration_color(cx.theme().link)
-            .hover(|this| {
-                this.text_color(cx.theme().link.opacity(0.8))
-                    .text_decoration_1()
-            })
+            .hover(|this| this.text_color(cx.theme().link_hover).text_decoration_1())
             .cursor_pointer()
             .child(
                 self.base
-                    .active(|this| {
-                        this.text_color(cx.theme().link.opacity(0.6))
-                            .text_decoration_1()
-                    })
+                    .active(|this| this.text_color(cx.theme().link_active).text_decoration_1())
                     .on_mouse_down(MouseButton::Left, |_, _, cx| {
                         cx.stop_propagation();
                     })
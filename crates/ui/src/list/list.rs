@@ -172,6 +172,7 @@ where
         let query_input = cx.new(|cx| {
             TextInput::new(window, cx)
                 .appearance(false)
+                .blur_on_escape(false)
                 .prefix(|_, cx| Icon::new(IconName::Search).text_color(cx.theme().muted_foreground))
                 .placeholder(t!("List.search_placeholder"))
                 .cleanable()
@@ -587,6 +588,7 @@ where
                     div()
                         .map(|this| match self.size {
                             Size::Small => this.py_0().px_1p5(),
+                            Size::XLarge => this.py_1p5().px_3(),
                             _ => this.py_1().px_2(),
                         })
                         .border_b_1()
@@ -247,6 +247,8 @@ pub struct Root {
     active_drawer: Option<ActiveDrawer>,
     pub(crate) active_modals: Vec<ActiveModal>,
     pub(super) focused_input: Option<Entity<TextInput>>,
+    /// The focus handle focused by the last `autofocus`, to let the first one win.
+    pub(crate) autofocused: Option<FocusHandle>,
     pub notification: Entity<NotificationList>,
     drawer_size: Option<DefiniteLength>,
     view: AnyView,
@@ -272,6 +274,7 @@ impl Root {
             active_drawer: None,
             active_modals: Vec::new(),
             focused_input: None,
+            autofocused: None,
             notification: cx.new(|cx| NotificationList::new(window, cx)),
             drawer_size: None,
             view,
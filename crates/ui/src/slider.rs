@@ -1,11 +1,21 @@
-use crate::{h_flex, tooltip::Tooltip, ActiveTheme, AxisExt};
+use crate::{
+    h_flex,
+    input::{
+        clamp_between, round_to_step, subscriber_step, InputEvent, NumberInput, NumberInputEvent,
+    },
+    tooltip::Tooltip,
+    ActiveTheme, AxisExt,
+};
 use gpui::{
-    canvas, div, prelude::FluentBuilder as _, px, AppContext as _, Axis, Bounds, Context,
-    DragMoveEvent, Empty, EntityId, EventEmitter, InteractiveElement, IntoElement, MouseButton,
-    MouseDownEvent, ParentElement as _, Pixels, Point, Render, StatefulInteractiveElement as _,
-    Styled, Window,
+    canvas, div, prelude::FluentBuilder as _, px, relative, AnyElement, AppContext as _, Axis,
+    Bounds, Context, DragMoveEvent, Empty, Entity, EntityId, EventEmitter, InteractiveElement,
+    IntoElement, MouseButton, MouseDownEvent, ParentElement as _, Pixels, Point, Render,
+    SharedString, StatefulInteractiveElement as _, Styled, Subscription, Window,
 };
 
+/// The ticks are not rendered when there are more steps than this, use the marks instead.
+const MAX_TICKS: usize = 100;
+
 #[derive(Clone)]
 pub struct DragThumb(EntityId);
 
@@ -28,7 +38,11 @@ pub struct Slider {
     value: f32,
     reverse: bool,
     percentage: f32,
+    ticks: bool,
+    marks: Vec<(f64, SharedString)>,
     bounds: Bounds<Pixels>,
+    number_input: Option<Entity<NumberInput>>,
+    _subscriptions: Vec<Subscription>,
 }
 
 impl Slider {
@@ -41,7 +55,11 @@ impl Slider {
             value: 0.0,
             percentage: 0.0,
             reverse: false,
+            ticks: false,
+            marks: vec![],
             bounds: Bounds::default(),
+            number_input: None,
+            _subscriptions: vec![],
         }
     }
 
@@ -81,6 +99,24 @@ impl Slider {
         self
     }
 
+    /// Set true to show the tick marks at each step along the track, default: false
+    ///
+    /// The ticks are skipped when there are more than 100 steps, use the [`Slider::marks`] instead.
+    pub fn ticks(mut self, ticks: bool) -> Self {
+        self.ticks = ticks;
+        self
+    }
+
+    /// Set the labeled marks to show along the track, click a label to move the thumb to it.
+    ///
+    /// ```ignore
+    /// Slider::horizontal().step(25.).marks(vec![(0., "Low".into()), (100., "High".into())])
+    /// ```
+    pub fn marks(mut self, marks: Vec<(f64, SharedString)>) -> Self {
+        self.marks = marks;
+        self
+    }
+
     /// Set the default value of the slider, default: 0.0
     pub fn default_value(mut self, value: f32) -> Self {
         self.value = value;
@@ -89,33 +125,130 @@ impl Slider {
     }
 
     /// Set the value of the slider.
-    pub fn set_value(&mut self, value: f32, _: &mut gpui::Window, cx: &mut gpui::Context<Self>) {
+    ///
+    /// The bound [`NumberInput`] is updated, without emitting the change event.
+    pub fn set_value(
+        &mut self,
+        value: f32,
+        window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) {
         self.value = value;
         self.update_thumb_pos();
+        self.sync_number_input(window, cx);
         cx.notify();
     }
 
+    /// Bind a [`NumberInput`] to edit the same value with the slider.
+    ///
+    /// The `min` and `max` of the slider are applied to the input, dragging the slider updates
+    /// the input, and typing or stepping in the input updates the slider by the slider `step`.
+    /// If the input has its own [`NumberInput::step`], the stepping is left to the input.
+    ///
+    /// The [`SliderEvent::Change`] is emitted for the changes from both of them.
+    ///
+    /// ```ignore
+    /// let slider = cx.new(|_| Slider::horizontal().min(0.).max(1.).step(0.05));
+    /// let input = cx.new(|cx| NumberInput::new(window, cx));
+    /// slider.update(cx, |slider, cx| slider.bind(&input, window, cx));
+    /// ```
+    pub fn bind(
+        &mut self,
+        number_input: &Entity<NumberInput>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let (min, max) = (Some(self.min as f64), Some(self.max as f64));
+        number_input.update(cx, |input, _| input.set_bounds(min, max));
+
+        self._subscriptions = vec![cx.subscribe_in(
+            number_input,
+            window,
+            |this, input, event: &NumberInputEvent, window, cx| match event {
+                NumberInputEvent::Input(InputEvent::Change(_)) => {
+                    // Keep the typed text, it is snapped to the step on blur.
+                    if let Some(value) = input.read(cx).value(cx) {
+                        this.value = this.clamp(value);
+                        this.update_thumb_pos();
+                        cx.emit(SliderEvent::Change(this.value));
+                        cx.notify();
+                    }
+                }
+                NumberInputEvent::Input(InputEvent::Blur) => this.sync_number_input(window, cx),
+                NumberInputEvent::Step(action) => {
+                    // The input with its own step has applied it by the change event.
+                    let input_step = input.read(cx).step_size();
+                    let Some(delta) = subscriber_step(input_step, this.step as f64, *action) else {
+                        return;
+                    };
+                    this.value = this.clamp(this.value as f64 + delta);
+                    this.update_thumb_pos();
+                    this.sync_number_input(window, cx);
+                    cx.emit(SliderEvent::Change(this.value));
+                    cx.notify();
+                }
+                _ => {}
+            },
+        )];
+        self.number_input = Some(number_input.clone());
+        self.sync_number_input(window, cx);
+    }
+
+    /// Round the value to the step, and clamp it into the `min` and `max`.
+    fn clamp(&self, value: f64) -> f32 {
+        // Convert the step by text, to keep `0.1_f32` as `0.1_f64` for the rounding precision.
+        let step = self.step.to_string().parse().unwrap_or(self.step as f64);
+        let value = round_to_step(value, step);
+        clamp_between(value, Some(self.min as f64), Some(self.max as f64)) as f32
+    }
+
+    fn sync_number_input(&self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(number_input) = self.number_input.as_ref() else {
+            return;
+        };
+
+        let value = self.value as f64;
+        number_input.update(cx, |input, cx| {
+            if input.value(cx) != Some(value) {
+                input.set_value(Some(value), window, cx);
+            }
+        });
+    }
+
     /// Get the value of the slider.
     pub fn value(&self) -> f32 {
         self.value
     }
 
     fn update_thumb_pos(&mut self) {
-        self.percentage = self.value.clamp(self.min, self.max) / self.max;
+        self.percentage = self.percentage_of(self.value);
+    }
+
+    /// The position of the value along the track, from the start of the fill bar.
+    fn percentage_of(&self, value: f32) -> f32 {
+        let range = self.max - self.min;
+        if range <= 0. {
+            return 0.;
+        }
+
+        let value = value.clamp(self.min, self.max);
+        match self.axis {
+            Axis::Horizontal => (value - self.min) / range,
+            Axis::Vertical => (self.max - value) / range,
+        }
     }
 
     /// Update value by mouse position
     fn update_value_by_position(
         &mut self,
         position: Point<Pixels>,
-        _: &mut gpui::Window,
+        window: &mut gpui::Window,
         cx: &mut gpui::Context<Self>,
     ) {
         let bounds = self.bounds;
         let axis = self.axis;
         let min = self.min;
         let max = self.max;
-        let step = self.step;
 
         let percentage = match axis {
             Axis::Horizontal => {
@@ -143,10 +276,10 @@ impl Slider {
             Axis::Vertical => max - (max - min) * percentage,
         };
 
-        let value = (value / step).round() * step;
-
-        self.percentage = percentage;
-        self.value = value.clamp(self.min, self.max);
+        // Snap the thumb to the nearest step.
+        self.value = self.clamp(value as f64);
+        self.update_thumb_pos();
+        self.sync_number_input(window, cx);
         cx.emit(SliderEvent::Change(self.value));
         cx.notify();
     }
@@ -206,6 +339,86 @@ impl Slider {
             .tooltip(move |window, cx| Tooltip::new(format!("{}", value)).build(window, cx))
     }
 
+    /// Position the element at the percentage along the track.
+    fn position_at<E: Styled>(&self, element: E, percentage: f32) -> E {
+        match (self.axis, self.reverse) {
+            (Axis::Horizontal, false) => element.left(relative(percentage)),
+            (Axis::Horizontal, true) => element.right(relative(percentage)),
+            (Axis::Vertical, false) => element.top(relative(percentage)),
+            (Axis::Vertical, true) => element.bottom(relative(percentage)),
+        }
+    }
+
+    fn render_ticks(&self, cx: &mut Context<Self>) -> Vec<AnyElement> {
+        let range = self.max - self.min;
+        let mut values = vec![];
+        if self.ticks && self.step > 0. && range > 0. {
+            let count = (range / self.step).round() as usize;
+            if count <= MAX_TICKS {
+                values.extend((0..=count).map(|i| self.min + self.step * i as f32));
+            }
+        }
+        values.extend(self.marks.iter().map(|(value, _)| *value as f32));
+
+        values
+            .into_iter()
+            .map(|value| {
+                let percentage = self.percentage_of(value);
+                let filled = percentage <= self.percentage;
+
+                self.position_at(div().absolute(), percentage)
+                    .map(|this| match self.axis {
+                        Axis::Horizontal => this.top(px(-2.)).ml(px(-0.5)).w(px(1.)).h(px(10.)),
+                        Axis::Vertical => this.left(px(-2.)).mt(px(-0.5)).h(px(1.)).w(px(10.)),
+                    })
+                    .map(|this| match filled {
+                        true => this.bg(cx.theme().slider_thumb),
+                        false => this.bg(cx.theme().slider_bar.opacity(0.5)),
+                    })
+                    .into_any_element()
+            })
+            .collect()
+    }
+
+    fn render_marks(&self, cx: &mut Context<Self>) -> Vec<AnyElement> {
+        self.marks
+            .iter()
+            .enumerate()
+            .map(|(ix, (value, label))| {
+                let value = *value;
+                let percentage = self.percentage_of(value as f32);
+
+                // A zero sized box to center the label at the mark.
+                self.position_at(div().absolute().flex().justify_center(), percentage)
+                    .map(|this| match self.axis {
+                        Axis::Horizontal => this.top(px(14.)).w_0(),
+                        Axis::Vertical => this.left(px(14.)).h_0().items_center(),
+                    })
+                    .child(
+                        div()
+                            .id(("slider-mark", ix))
+                            .whitespace_nowrap()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .cursor_pointer()
+                            .on_mouse_down(
+                                MouseButton::Left,
+                                cx.listener(move |this, _, window, cx| {
+                                    cx.stop_propagation();
+                                    this.value = this.clamp(value);
+                                    this.update_thumb_pos();
+                                    this.sync_number_input(window, cx);
+                                    cx.emit(SliderEvent::Change(this.value));
+                                    cx.notify();
+                                }),
+                            )
+                            .child(label.clone()),
+                    )
+                    .into_any_element()
+            })
+            .collect()
+    }
+
     fn on_mouse_down(
         &mut self,
         event: &MouseDownEvent,
@@ -225,12 +438,18 @@ impl Render for Slider {
             Axis::Vertical => self.percentage * self.bounds.size.height,
         };
 
+        let has_marks = !self.marks.is_empty();
+
         div()
             .id("slider")
             .flex_1()
             .when(self.axis.is_vertical(), |this| {
                 this.flex().items_center().justify_center()
             })
+            .when(has_marks, |this| match self.axis {
+                Axis::Horizontal => this.pb_4(),
+                Axis::Vertical => this.pr_8(),
+            })
             .child(
                 h_flex()
                     .on_mouse_down(MouseButton::Left, cx.listener(Self::on_mouse_down))
@@ -264,6 +483,8 @@ impl Render for Slider {
                                     .bg(cx.theme().slider_bar)
                                     .rounded_full(),
                             )
+                            .children(self.render_ticks(cx))
+                            .children(self.render_marks(cx))
                             .child(self.render_thumb(thumb_bar_size, window, cx))
                             .child({
                                 let view = cx.entity().clone();
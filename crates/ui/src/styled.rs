@@ -2,11 +2,12 @@ use std::fmt::{self, Display, Formatter};
 
 use crate::{
     scroll::{Scrollable, ScrollbarAxis},
-    ActiveTheme,
+    theme::box_shadow,
+    ActiveTheme, ThemeDensity,
 };
 use gpui::{
-    div, px, App, Axis, DefiniteLength, Div, Edges, Element, ElementId, EntityId, FocusHandle,
-    Pixels, Styled, Window,
+    div, px, rems, App, Axis, DefiniteLength, Div, Edges, Element, ElementId, EntityId,
+    FocusHandle, Pixels, Styled, Window,
 };
 use serde::{Deserialize, Serialize};
 
@@ -128,10 +129,28 @@ pub trait StyledExt: Styled + Sized {
         }
     }
 
-    /// Render a border with a width of 1px, color ring color
+    /// Render the focus ring border, with the `ring` color and `ring_width` of the theme.
+    ///
+    /// The 1px border is kept, and the ring wider than it is drawn as an outer shadow,
+    /// so the layout is not shifted by the focus.
     #[inline]
     fn focused_border(self, cx: &App) -> Self {
-        self.border_color(cx.theme().ring)
+        let ring = cx.theme().ring;
+        let spread = cx.theme().ring_width - px(1.);
+
+        let this = self.border_color(ring);
+        if spread > px(0.) {
+            this.shadow(smallvec::smallvec![box_shadow(0., 0., 0., spread, ring)])
+        } else {
+            this
+        }
+    }
+
+    /// Render as disabled, with the `disabled_opacity` of the theme.
+    #[inline]
+    fn disabled_style(self, cx: &App) -> Self {
+        self.opacity(cx.theme().disabled_opacity)
+            .cursor_not_allowed()
     }
 
     /// Wraps the element in a ScrollView.
@@ -177,6 +196,8 @@ pub enum Size {
     #[default]
     Medium,
     Large,
+    /// Extra large size, for large touch targets (e.g. tablet layouts).
+    XLarge,
 }
 
 impl Size {
@@ -187,10 +208,34 @@ impl Size {
             Size::XSmall => px(26.),
             Size::Small => px(30.),
             Size::Large => px(40.),
+            Size::XLarge => px(48.),
             _ => px(32.),
         }
     }
 
+    /// Returns the horizontal padding of the input in rems.
+    #[inline]
+    fn input_padding_x(&self) -> f32 {
+        match self {
+            Size::XLarge => 1.5,
+            Size::Large => 1.25,
+            Size::Medium => 0.75,
+            Size::XSmall | Size::Small | Size::Size(_) => 0.5,
+        }
+    }
+
+    /// Returns the vertical padding of the input in rems.
+    #[inline]
+    fn input_padding_y(&self) -> f32 {
+        match self {
+            Size::XLarge => 1.25,
+            Size::Large => 1.25,
+            Size::Medium => 0.5,
+            Size::XSmall => 0.,
+            Size::Small | Size::Size(_) => 0.25,
+        }
+    }
+
     /// Returns the padding for a table cell.
     #[inline]
     pub fn table_cell_padding(&self) -> Edges<Pixels> {
@@ -213,6 +258,12 @@ impl Size {
                 left: px(12.),
                 right: px(12.),
             },
+            Size::XLarge => Edges {
+                top: px(12.),
+                bottom: px(12.),
+                left: px(16.),
+                right: px(16.),
+            },
             _ => Edges {
                 top: px(4.),
                 bottom: px(4.),
@@ -229,6 +280,7 @@ impl Size {
             Size::Small => Size::XSmall,
             Size::Medium => Size::Small,
             Size::Large => Size::Medium,
+            Size::XLarge => Size::Large,
             Size::Size(val) => Size::Size(*val * 0.2),
         }
     }
@@ -239,7 +291,8 @@ impl Size {
             Size::XSmall => Size::Small,
             Size::Small => Size::Medium,
             Size::Medium => Size::Large,
-            Size::Large => Size::Large,
+            Size::Large => Size::XLarge,
+            Size::XLarge => Size::XLarge,
             Size::Size(val) => Size::Size(*val * 1.2),
         }
     }
@@ -287,6 +340,11 @@ pub trait Sizable: Sized {
     fn large(self) -> Self {
         self.with_size(Size::Large)
     }
+
+    /// Set to Size::XLarge
+    fn xlarge(self) -> Self {
+        self.with_size(Size::XLarge)
+    }
 }
 
 #[allow(unused)]
@@ -297,6 +355,12 @@ pub trait StyleSized<T: Styled> {
     fn input_pr(self, size: Size) -> Self;
     fn input_px(self, size: Size) -> Self;
     fn input_py(self, size: Size) -> Self;
+    /// Apply the input paddings and height, the paddings are scaled by the `density`.
+    fn input_size_with_density(self, size: Size, density: ThemeDensity) -> Self;
+    fn input_pl_with_density(self, size: Size, density: ThemeDensity) -> Self;
+    fn input_pr_with_density(self, size: Size, density: ThemeDensity) -> Self;
+    fn input_px_with_density(self, size: Size, density: ThemeDensity) -> Self;
+    fn input_py_with_density(self, size: Size, density: ThemeDensity) -> Self;
     fn input_h(self, size: Size) -> Self;
     fn list_size(self, size: Size) -> Self;
     fn list_px(self, size: Size) -> Self;
@@ -316,6 +380,7 @@ impl<T: Styled> StyleSized<T> for T {
             Size::Small => self.text_sm(),
             Size::Medium => self.text_base(),
             Size::Large => self.text_lg(),
+            Size::XLarge => self.text_xl(),
             Size::Size(size) => self.text_size(size),
         }
     }
@@ -327,45 +392,55 @@ impl<T: Styled> StyleSized<T> for T {
 
     #[inline]
     fn input_pl(self, size: Size) -> Self {
-        match size {
-            Size::Large => self.pl_5(),
-            Size::Medium => self.pl_3(),
-            _ => self.pl_2(),
-        }
+        self.input_pl_with_density(size, ThemeDensity::Cozy)
     }
 
     #[inline]
     fn input_pr(self, size: Size) -> Self {
-        match size {
-            Size::Large => self.pr_5(),
-            Size::Medium => self.pr_3(),
-            _ => self.pr_2(),
-        }
+        self.input_pr_with_density(size, ThemeDensity::Cozy)
     }
 
     #[inline]
     fn input_px(self, size: Size) -> Self {
-        match size {
-            Size::Large => self.px_5(),
-            Size::Medium => self.px_3(),
-            _ => self.px_2(),
-        }
+        self.input_px_with_density(size, ThemeDensity::Cozy)
     }
 
     #[inline]
     fn input_py(self, size: Size) -> Self {
-        match size {
-            Size::Large => self.py_5(),
-            Size::Medium => self.py_2(),
-            Size::Small => self.py_1(),
-            Size::XSmall => self.py_0(),
-            _ => self.py_1(),
-        }
+        self.input_py_with_density(size, ThemeDensity::Cozy)
+    }
+
+    #[inline]
+    fn input_size_with_density(self, size: Size, density: ThemeDensity) -> Self {
+        self.input_px_with_density(size, density)
+            .input_py_with_density(size, density)
+            .input_h(size)
+    }
+
+    #[inline]
+    fn input_pl_with_density(self, size: Size, density: ThemeDensity) -> Self {
+        self.pl(rems(size.input_padding_x() * density.padding_scale()))
+    }
+
+    #[inline]
+    fn input_pr_with_density(self, size: Size, density: ThemeDensity) -> Self {
+        self.pr(rems(size.input_padding_x() * density.padding_scale()))
+    }
+
+    #[inline]
+    fn input_px_with_density(self, size: Size, density: ThemeDensity) -> Self {
+        self.px(rems(size.input_padding_x() * density.padding_scale()))
+    }
+
+    #[inline]
+    fn input_py_with_density(self, size: Size, density: ThemeDensity) -> Self {
+        self.py(rems(size.input_padding_y() * density.padding_scale()))
     }
 
     #[inline]
     fn input_h(self, size: Size) -> Self {
         match size {
+            Size::XLarge => self.h(px(56.)),
             Size::Large => self.h_11(),
             Size::Medium => self.h_8(),
             Size::Small => self.h(px(26.)),
@@ -384,6 +459,7 @@ impl<T: Styled> StyleSized<T> for T {
     fn list_px(self, size: Size) -> Self {
         match size {
             Size::Small => self.px_2(),
+            Size::XLarge => self.px_4(),
             _ => self.px_3(),
         }
     }
@@ -391,6 +467,7 @@ impl<T: Styled> StyleSized<T> for T {
     #[inline]
     fn list_py(self, size: Size) -> Self {
         match size {
+            Size::XLarge => self.py_3(),
             Size::Large => self.py_2(),
             Size::Medium => self.py_1(),
             Size::Small => self.py_0p5(),
@@ -401,6 +478,7 @@ impl<T: Styled> StyleSized<T> for T {
     #[inline]
     fn size_with(self, size: Size) -> Self {
         match size {
+            Size::XLarge => self.size(px(56.)),
             Size::Large => self.size_11(),
             Size::Medium => self.size_8(),
             Size::Small => self.size_5(),
@@ -427,6 +505,7 @@ impl<T: Styled> StyleSized<T> for T {
         match size {
             Size::XSmall => self.text_xs(),
             Size::Small => self.text_sm(),
+            Size::XLarge => self.text_lg(),
             _ => self.text_base(),
         }
     }
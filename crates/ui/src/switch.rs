@@ -138,10 +138,12 @@ impl Element for Switch {
 
             let (bg_width, bg_height) = match self.size {
                 Size::XSmall | Size::Small => (px(28.), px(16.)),
+                Size::XLarge => (px(44.), px(24.)),
                 _ => (px(36.), px(20.)),
             };
             let bar_width = match self.size {
                 Size::XSmall | Size::Small => px(12.),
+                Size::XLarge => px(20.),
                 _ => px(16.),
             };
             let inset = px(2.);
@@ -229,6 +231,7 @@ impl Element for Switch {
                             this.child(div().line_height(bg_height).child(label).map(|this| {
                                 match self.size {
                                     Size::XSmall | Size::Small => this.text_sm(),
+                                    Size::XLarge => this.text_lg(),
                                     _ => this.text_base(),
                                 }
                             }))
@@ -57,6 +57,7 @@ impl TabVariant {
                 _ => px(24.),
             },
             Size::Large => px(36.),
+            Size::XLarge => px(44.),
             _ => match self {
                 TabVariant::Underline => px(36.),
                 _ => px(32.),
@@ -81,6 +82,11 @@ impl TabVariant {
                 TabVariant::Segmented => px(28.),
                 TabVariant::Underline => px(32.),
             },
+            Size::XLarge => match self {
+                TabVariant::Tab | TabVariant::Outline | TabVariant::Pill => px(44.),
+                TabVariant::Segmented => px(36.),
+                TabVariant::Underline => px(38.),
+            },
             _ => match self {
                 TabVariant::Tab => px(30.),
                 TabVariant::Outline | TabVariant::Pill => px(26.),
@@ -96,6 +102,7 @@ impl TabVariant {
             Size::XSmall => px(8.),
             Size::Small => px(10.),
             Size::Large => px(16.),
+            Size::XLarge => px(20.),
             _ => px(12.),
         };
 
@@ -136,6 +143,14 @@ impl TabVariant {
                 },
                 _ => Edges::all(px(0.)),
             },
+            Size::XLarge => match self {
+                TabVariant::Underline => Edges {
+                    top: px(6.),
+                    bottom: px(7.),
+                    ..Default::default()
+                },
+                _ => Edges::all(px(0.)),
+            },
             _ => match self {
                 TabVariant::Underline => Edges {
                     top: px(3.),
@@ -590,6 +605,7 @@ impl RenderOnce for Tab {
             .map(|this| match self.size {
                 Size::XSmall => this.text_xs(),
                 Size::Large => this.text_base(),
+                Size::XLarge => this.text_lg(),
                 _ => this.text_sm(),
             })
             .bg(tab_style.bg)
@@ -628,6 +644,7 @@ impl RenderOnce for Tab {
                                     Size::XSmall => this.size_2p5(),
                                     Size::Small => this.size_3p5(),
                                     Size::Large => this.size_4(),
+                                    Size::XLarge => this.size_5(),
                                     _ => this.size_4(),
                                 }))
                         }
@@ -158,6 +158,7 @@ impl RenderOnce for TabBar {
         let default_gap = match self.size {
             Size::Small | Size::XSmall => px(8.),
             Size::Large => px(16.),
+            Size::XLarge => px(20.),
             _ => px(12.),
         };
         let (bg, paddings, gap) = match self.variant {
@@ -178,6 +179,7 @@ impl RenderOnce for TabBar {
                     Size::XSmall => px(3.),
                     Size::Small => px(3.),
                     Size::Large => px(6.),
+                    Size::XLarge => px(8.),
                     _ => px(5.),
                 };
                 let padding = Edges {
@@ -194,6 +196,7 @@ impl RenderOnce for TabBar {
                     Size::XSmall => px(8.),
                     Size::Small => px(10.),
                     Size::Large => px(16.),
+                    Size::XLarge => px(20.),
                     _ => px(12.),
                 };
 
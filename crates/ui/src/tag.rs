@@ -153,6 +153,7 @@ impl RenderOnce for Tag {
             .text_xs()
             .map(|this| match self.size {
                 Size::XSmall | Size::Small => this.px_1p5().py_0().rounded(cx.theme().radius / 2.),
+                Size::XLarge => this.px_3().py_1().text_sm().rounded(cx.theme().radius),
                 _ => this.px_2p5().py_0p5().rounded(cx.theme().radius),
             })
             .bg(bg)
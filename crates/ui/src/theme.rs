@@ -427,6 +427,59 @@ impl ThemeColor {
             window_border: hsl(240.0, 3.7, 28.0),
         }
     }
+
+    /// The high-contrast variant of the light theme, for the WCAG AAA contrast of text and borders.
+    pub fn high_contrast_light() -> Self {
+        Self {
+            accent: hsl(0.0, 0.0, 85.0),
+            accent_foreground: hsl(0.0, 0.0, 0.0),
+            border: hsl(0.0, 0.0, 20.0),
+            caret: hsl(0.0, 0.0, 0.0),
+            card_foreground: hsl(0.0, 0.0, 0.0),
+            foreground: hsl(0.0, 0.0, 0.0),
+            input: hsl(0.0, 0.0, 25.0),
+            link: hsl(221.0, 100.0, 32.0),
+            link_active: hsl(221.0, 100.0, 20.0),
+            link_hover: hsl(221.0, 100.0, 25.0),
+            list_active_border: hsl(221.0, 100.0, 32.0),
+            muted_foreground: hsl(0.0, 0.0, 28.0),
+            popover_foreground: hsl(0.0, 0.0, 0.0),
+            primary: hsl(0.0, 0.0, 0.0),
+            primary_foreground: hsl(0.0, 0.0, 100.0),
+            ring: hsl(221.0, 100.0, 40.0),
+            selection: hsl(211.0, 100.0, 75.0),
+            table_active_border: hsl(221.0, 100.0, 32.0),
+            ..Self::light()
+        }
+    }
+
+    /// The high-contrast variant of the dark theme, for the WCAG AAA contrast of text and borders.
+    pub fn high_contrast_dark() -> Self {
+        Self {
+            accent: hsl(0.0, 0.0, 25.0),
+            accent_foreground: hsl(0.0, 0.0, 100.0),
+            background: hsl(0.0, 0.0, 0.0),
+            border: hsl(0.0, 0.0, 75.0),
+            caret: hsl(0.0, 0.0, 100.0),
+            card: hsl(0.0, 0.0, 0.0),
+            card_foreground: hsl(0.0, 0.0, 100.0),
+            foreground: hsl(0.0, 0.0, 100.0),
+            input: hsl(0.0, 0.0, 70.0),
+            link: hsl(210.0, 100.0, 75.0),
+            link_active: hsl(210.0, 100.0, 65.0),
+            link_hover: hsl(210.0, 100.0, 85.0),
+            list_active_border: hsl(48.0, 100.0, 60.0),
+            muted_foreground: hsl(0.0, 0.0, 75.0),
+            popover: hsl(0.0, 0.0, 0.0),
+            popover_foreground: hsl(0.0, 0.0, 100.0),
+            primary: hsl(0.0, 0.0, 100.0),
+            primary_foreground: hsl(0.0, 0.0, 0.0),
+            ring: hsl(48.0, 100.0, 60.0),
+            selection: hsl(211.0, 100.0, 35.0),
+            table_active_border: hsl(48.0, 100.0, 60.0),
+            ..Self::dark()
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -437,7 +490,15 @@ pub struct Theme {
     pub font_family: SharedString,
     pub font_size: Pixels,
     pub radius: Pixels,
+    /// The width of the focus ring, default is 1px.
+    ///
+    /// The 1px border is kept, the ring wider than it is drawn as an outer shadow.
+    pub ring_width: Pixels,
     pub shadow: bool,
+    /// The opacity of the disabled components, default is 0.5.
+    pub disabled_opacity: f32,
+    /// The density of the inputs and buttons, default is `ThemeDensity::Cozy`.
+    pub density: ThemeDensity,
     pub transparent: Hsla,
     /// Show the scrollbar mode, default: Scrolling
     pub scrollbar_show: ScrollbarShow,
@@ -445,6 +506,8 @@ pub struct Theme {
     pub tile_grid_size: Pixels,
     /// The shadow of the tile panel.
     pub tile_shadow: bool,
+    /// Use the high-contrast colors, default is false.
+    pub high_contrast: bool,
 }
 
 impl Deref for Theme {
@@ -584,11 +647,29 @@ impl Theme {
         }
     }
 
+    /// Enable or disable the high-contrast colors, keeping the current theme mode.
+    pub fn set_high_contrast(high_contrast: bool, window: Option<&mut Window>, cx: &mut App) {
+        let mode = cx
+            .try_global::<Theme>()
+            .map_or(ThemeMode::default(), |theme| theme.mode);
+
+        Self::apply(mode, high_contrast, window, cx);
+    }
+
     pub fn change(mode: impl Into<ThemeMode>, window: Option<&mut Window>, cx: &mut App) {
-        let mode = mode.into();
-        let colors = match mode {
-            ThemeMode::Light => ThemeColor::light(),
-            ThemeMode::Dark => ThemeColor::dark(),
+        let high_contrast = cx
+            .try_global::<Theme>()
+            .map_or(false, |theme| theme.high_contrast);
+
+        Self::apply(mode.into(), high_contrast, window, cx);
+    }
+
+    fn apply(mode: ThemeMode, high_contrast: bool, window: Option<&mut Window>, cx: &mut App) {
+        let colors = match (mode, high_contrast) {
+            (ThemeMode::Light, false) => ThemeColor::light(),
+            (ThemeMode::Dark, false) => ThemeColor::dark(),
+            (ThemeMode::Light, true) => ThemeColor::high_contrast_light(),
+            (ThemeMode::Dark, true) => ThemeColor::high_contrast_dark(),
         };
 
         if !cx.has_global::<Theme>() {
@@ -599,6 +680,7 @@ impl Theme {
         let theme = cx.global_mut::<Theme>();
 
         theme.mode = mode;
+        theme.high_contrast = high_contrast;
         theme.colors = colors;
 
         if let Some(window) = window {
@@ -622,10 +704,14 @@ impl From<ThemeColor> for Theme {
                 "FreeMono".into()
             },
             radius: px(4.),
+            ring_width: px(1.),
             shadow: true,
+            disabled_opacity: 0.5,
+            density: ThemeDensity::default(),
             scrollbar_show: ScrollbarShow::default(),
             tile_grid_size: px(8.),
             tile_shadow: true,
+            high_contrast: false,
             colors,
         }
     }
@@ -656,6 +742,34 @@ impl ThemeMode {
     }
 }
 
+/// The density of the input family and buttons, to tighten the UI for the data-dense apps.
+///
+/// This scales the paddings on top of the `Size` of each component.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ThemeDensity {
+    /// The reduced paddings.
+    Compact,
+    /// This is the default.
+    #[default]
+    Cozy,
+}
+
+impl ThemeDensity {
+    #[inline]
+    pub fn is_compact(&self) -> bool {
+        matches!(self, Self::Compact)
+    }
+
+    /// Returns the multiplier of the paddings.
+    pub fn padding_scale(&self) -> f32 {
+        match self {
+            Self::Compact => 0.5,
+            Self::Cozy => 1.,
+        }
+    }
+}
+
 impl From<WindowAppearance> for ThemeMode {
     fn from(appearance: WindowAppearance) -> Self {
         match appearance {
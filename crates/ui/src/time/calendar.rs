@@ -1,30 +1,98 @@
+//! The calendar can be used standalone, to render inline in a page (e.g. a sidebar)
+//! without the input field of the [`DatePicker`](super::date_picker::DatePicker).
+//!
+//! ```ignore
+//! let calendar = cx.new(|cx| {
+//!     Calendar::new(window, cx)
+//!         .number_of_months(2)
+//!         .presets(vec![DateRangePreset::single("Today", today)])
+//! });
+//! cx.subscribe(&calendar, |_, _, ev: &CalendarEvent, _| match ev {
+//!     CalendarEvent::Selected(date) => println!("selected: {}", date),
+//!     CalendarEvent::MonthChanged { year, month } => println!("viewing: {}-{}", year, month),
+//! })
+//! .detach();
+//! ```
+
 use std::borrow::Cow;
+use std::time::{Duration, Instant};
 
-use chrono::{Datelike, Local, NaiveDate};
+use chrono::{Datelike, Local, NaiveDate, Weekday};
 use gpui::{
-    prelude::FluentBuilder as _, px, relative, ClickEvent, Context, ElementId, EventEmitter,
-    FocusHandle, InteractiveElement, IntoElement, ParentElement, Render, SharedString,
-    StatefulInteractiveElement, Styled, Window,
+    actions, div, prelude::FluentBuilder as _, px, relative, rems, App, AppContext as _, Axis,
+    ClickEvent, Context, ElementId, Entity, EventEmitter, FocusHandle, Focusable,
+    InteractiveElement, IntoElement, KeyBinding, KeyDownEvent, ParentElement, Rems, Render,
+    ScrollHandle, SharedString, StatefulInteractiveElement, Styled, Subscription, Window,
 };
 use rust_i18n::t;
 
 use crate::{
     button::{Button, ButtonVariants as _},
-    h_flex, v_flex, ActiveTheme, Disableable as _, IconName, Selectable, Sizable, Size,
+    h_flex,
+    indicator::Indicator,
+    input::{InputEvent, TextInput},
+    tooltip::Tooltip,
+    v_flex, ActiveTheme, AxisExt as _, Disableable as _, IconName, Selectable, Sizable, Size,
+    StyledExt as _, ThemeDensity,
+};
+
+use super::date_picker::DateRangePreset;
+use super::utils::{
+    days_in_month, parse_date, parse_relative_offset, quarter_of, quarter_range, typeahead_day,
+    typeahead_match, week_range, year_range,
 };
 
-use super::utils::days_in_month;
+actions!(
+    calendar,
+    [
+        PrevMonth,
+        NextMonth,
+        PrevYear,
+        NextYear,
+        ExtendPrevDay,
+        ExtendNextDay,
+        ExtendPrevWeek,
+        ExtendNextWeek,
+        ConfirmRange
+    ]
+);
+
+const KEY_CONTEXT: &str = "Calendar";
+
+/// The date formats accepted by the quick jump input, besides the relative offset like `+2w`.
+const QUICK_JUMP_FORMATS: [&str; 4] = ["%Y-%m-%d", "%Y/%m/%d", "%Y.%m.%d", "%Y%m%d"];
+
+/// The keys typed within this duration are joined for the typeahead.
+const TYPEAHEAD_TIMEOUT: Duration = Duration::from_secs(1);
+
+pub fn init(cx: &mut App) {
+    cx.bind_keys([
+        KeyBinding::new("pageup", PrevMonth, Some(KEY_CONTEXT)),
+        KeyBinding::new("pagedown", NextMonth, Some(KEY_CONTEXT)),
+        KeyBinding::new("shift-pageup", PrevYear, Some(KEY_CONTEXT)),
+        KeyBinding::new("shift-pagedown", NextYear, Some(KEY_CONTEXT)),
+        KeyBinding::new("shift-left", ExtendPrevDay, Some(KEY_CONTEXT)),
+        KeyBinding::new("shift-right", ExtendNextDay, Some(KEY_CONTEXT)),
+        KeyBinding::new("shift-up", ExtendPrevWeek, Some(KEY_CONTEXT)),
+        KeyBinding::new("shift-down", ExtendNextWeek, Some(KEY_CONTEXT)),
+        KeyBinding::new("enter", ConfirmRange, Some(KEY_CONTEXT)),
+    ]);
+}
 
 pub enum CalendarEvent {
     /// The user selected a date.
     Selected(Date),
+    /// The visible month (of the first months view) is changed by the user navigation.
+    MonthChanged { year: i32, month: u32 },
 }
 
 /// The date of the calendar.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Date {
     Single(Option<NaiveDate>),
     Range(Option<NaiveDate>, Option<NaiveDate>),
+    /// The selected days in ascending order, toggled by click.
+    Multiple(Vec<NaiveDate>),
 }
 
 impl std::fmt::Display for Date {
@@ -36,6 +104,14 @@ impl std::fmt::Display for Date {
             Self::Range(None, None) => write!(f, "nil"),
             Self::Range(Some(start), None) => write!(f, "{} - nil", start),
             Self::Range(None, Some(end)) => write!(f, "nil - {}", end),
+            Self::Multiple(dates) if dates.is_empty() => write!(f, "nil"),
+            Self::Multiple(dates) => {
+                let dates = dates
+                    .iter()
+                    .map(|date| date.to_string())
+                    .collect::<Vec<_>>();
+                write!(f, "{}", dates.join(", "))
+            }
         }
     }
 }
@@ -58,6 +134,7 @@ impl Date {
         match self {
             Self::Single(d) => Some(v) == *d,
             Self::Range(start, end) => Some(v) == *start || Some(v) == *end,
+            Self::Multiple(dates) => dates.contains(&v),
         }
     }
 
@@ -65,6 +142,44 @@ impl Date {
         matches!(self, Self::Single(_))
     }
 
+    fn is_range(&self) -> bool {
+        matches!(self, Self::Range(_, _))
+    }
+
+    /// Returns true if the date is in the multiple mode.
+    pub fn is_multiple(&self) -> bool {
+        matches!(self, Self::Multiple(_))
+    }
+
+    /// Returns the number of the selected days in the multiple mode, or 0.
+    pub fn count(&self) -> usize {
+        match self {
+            Self::Multiple(dates) => dates.len(),
+            _ => 0,
+        }
+    }
+
+    /// Select or deselect the day in the multiple mode, up to `max` days if set.
+    ///
+    /// Returns false if not in the multiple mode, or the day can't be selected by the `max`.
+    pub(super) fn toggle(&mut self, date: NaiveDate, max: Option<usize>) -> bool {
+        let Self::Multiple(dates) = self else {
+            return false;
+        };
+
+        match dates.binary_search(&date) {
+            Ok(ix) => {
+                dates.remove(ix);
+                true
+            }
+            Err(_) if max.map_or(false, |max| dates.len() >= max) => false,
+            Err(ix) => {
+                dates.insert(ix, date);
+                true
+            }
+        }
+    }
+
     fn is_in_range(&self, v: &NaiveDate) -> bool {
         let v = *v;
         match self {
@@ -83,9 +198,11 @@ impl Date {
         }
     }
 
+    /// Check if the date has any value, for a range, at least one of the start or end is set.
     pub fn is_some(&self) -> bool {
         match self {
-            Self::Single(Some(_)) | Self::Range(Some(_), _) => true,
+            Self::Single(Some(_)) | Self::Range(Some(_), _) | Self::Range(_, Some(_)) => true,
+            Self::Multiple(dates) => !dates.is_empty(),
             _ => false,
         }
     }
@@ -95,14 +212,17 @@ impl Date {
         match self {
             Self::Range(Some(_), Some(_)) => true,
             Self::Single(Some(_)) => true,
+            Self::Multiple(dates) => !dates.is_empty(),
             _ => false,
         }
     }
 
+    /// Returns the date, the start of the range, or the first of the multiple days.
     pub fn start(&self) -> Option<NaiveDate> {
         match self {
             Self::Single(Some(date)) => Some(*date),
             Self::Range(Some(start), _) => Some(*start),
+            Self::Multiple(dates) => dates.first().copied(),
             _ => None,
         }
     }
@@ -115,21 +235,132 @@ impl Date {
     }
 
     /// Return formatted date string.
+    ///
+    /// For a partial range, the missing side is displayed as `…`, e.g.: `2024/03/01 - …`.
+    ///
+    /// Returns `None` if the date [`is_some`](Self::is_some) is false.
     pub fn format(&self, format: &str) -> Option<SharedString> {
+        self.format_with_separator(format, " - ")
+    }
+
+    /// Return formatted date string, the range dates are joined with the `separator`.
+    ///
+    /// The multiple days are joined with `, `.
+    pub fn format_with_separator(&self, format: &str, separator: &str) -> Option<SharedString> {
         match self {
             Self::Single(Some(date)) => Some(date.format(format).to_string().into()),
-            Self::Range(Some(start), Some(end)) => {
-                Some(format!("{} - {}", start.format(format), end.format(format)).into())
+            Self::Range(Some(start), Some(end)) => Some(
+                format!(
+                    "{}{}{}",
+                    start.format(format),
+                    separator,
+                    end.format(format)
+                )
+                .into(),
+            ),
+            Self::Range(Some(start), None) => {
+                Some(format!("{}{}…", start.format(format), separator).into())
+            }
+            Self::Range(None, Some(end)) => {
+                Some(format!("…{}{}", separator, end.format(format)).into())
+            }
+            Self::Multiple(dates) if !dates.is_empty() => {
+                let dates = dates
+                    .iter()
+                    .map(|date| date.format(format).to_string())
+                    .collect::<Vec<_>>();
+                Some(dates.join(", ").into())
             }
             _ => None,
         }
     }
+
+    /// Return the text to display the date, the same as the [`DatePicker`](super::date_picker::DatePicker),
+    /// e.g. in a table cell.
+    ///
+    /// The range dates are joined with the `range_separator`, and the missing endpoint is displayed
+    /// as `…`. It returns an empty string if there is no date.
+    pub fn display(&self, format: &str, range_separator: &str) -> SharedString {
+        self.format_with_separator(format, range_separator)
+            .unwrap_or_default()
+    }
+
+    /// Return the text to display the date in the granularity, e.g. `2024 Q3` in `Granularity::Quarter`.
+    pub fn display_in(
+        &self,
+        granularity: Granularity,
+        format: &str,
+        range_separator: &str,
+    ) -> Option<SharedString> {
+        match granularity {
+            Granularity::Day | Granularity::Week | Granularity::Month => {
+                self.format_with_separator(format, range_separator)
+            }
+            Granularity::Quarter => self
+                .start()
+                .map(|date| format!("{} Q{}", date.format(format), quarter_of(&date)).into()),
+            Granularity::Year => self
+                .start()
+                .map(|date| date.format(format).to_string().into()),
+        }
+    }
+}
+
+/// The endpoint of the range date to update by the next selection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeEndpoint {
+    Start,
+    End,
+}
+
+/// The density of the day cells in the calendar.
+///
+/// The `Size` of the calendar decides the text size, and the density makes the cells
+/// smaller or larger on top of it, so both are applied.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Density {
+    /// The smaller cells, for the dashboards.
+    Compact,
+    /// This is the default.
+    #[default]
+    Normal,
+    /// The larger cells, for the touch devices.
+    Comfortable,
+}
+
+/// The shape of the selected and in-range day cells in the calendar.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionShape {
+    /// The rounded square by the theme radius, this is the default.
+    #[default]
+    Rounded,
+    /// The circle, the range is a connected bar with the round ends.
+    Circle,
+    /// The square, the range is a connected bar with the square ends.
+    Square,
+}
+
+/// The granularity of the date to select in the calendar.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Granularity {
+    /// Select a day, this is the default.
+    #[default]
+    Day,
+    /// Select a week, the date will be a range spanning the week (Sunday to Saturday).
+    Week,
+    /// Select a month, the date will be the first day of the month.
+    Month,
+    /// Select a quarter, the date will be a range spanning the quarter.
+    Quarter,
+    /// Select a year, the date will be a range spanning the year.
+    Year,
 }
 
 #[derive(Debug, PartialEq, Eq)]
 enum ViewMode {
     Day,
     Month,
+    Quarter,
     Year,
 }
 
@@ -142,6 +373,10 @@ impl ViewMode {
         matches!(self, Self::Month)
     }
 
+    fn is_quarter(&self) -> bool {
+        matches!(self, Self::Quarter)
+    }
+
     fn is_year(&self) -> bool {
         matches!(self, Self::Year)
     }
@@ -179,6 +414,14 @@ pub enum Matcher {
     /// })
     /// Will match the days that are between 2020-01-01 and 2020-01-03.
     Range(RangeMatcher),
+    /// Match the days that matched by any of the matchers.
+    Any(Vec<Matcher>),
+    /// Match the days that matched by all of the matchers.
+    All(Vec<Matcher>),
+    /// Match the days that not matched by the matcher.
+    Not(Box<Matcher>),
+    /// Match the days that matched by the matcher, with the reason to show in the tooltip.
+    Reason(Box<Matcher>, SharedString),
 }
 
 impl From<Vec<u32>> for Matcher {
@@ -196,6 +439,67 @@ impl Matcher {
         Matcher::Range(RangeMatcher { from, to })
     }
 
+    /// Combine the matchers, match the days that matched by any of them.
+    ///
+    /// The composite matchers are evaluated for each day cell of the calendar
+    /// (up to 42 cells per month), and short-circuit on the first match,
+    /// so keep the tree shallow for many months.
+    ///
+    /// ```ignore
+    /// // Weekends or holidays, but not the exceptions.
+    /// Matcher::all(vec![
+    ///     Matcher::any(vec![Matcher::DayOfWeek(vec![0, 6]), holidays]),
+    ///     Matcher::not(exceptions),
+    /// ])
+    /// ```
+    pub fn any(matchers: Vec<Matcher>) -> Self {
+        Matcher::Any(matchers)
+    }
+
+    /// Combine the matchers, match the days that matched by all of them.
+    ///
+    /// The same as [`Matcher::any`], this is short-circuit on the first unmatched.
+    pub fn all(matchers: Vec<Matcher>) -> Self {
+        Matcher::All(matchers)
+    }
+
+    /// Match the days that not matched by the matcher.
+    pub fn not(matcher: Matcher) -> Self {
+        Matcher::Not(Box::new(matcher))
+    }
+
+    /// Attach a reason to the matched days, to show in the tooltip of the disabled days.
+    ///
+    /// ```ignore
+    /// Matcher::any(vec![
+    ///     Matcher::interval(Some(today), None).with_reason("Past date"),
+    ///     booked.with_reason("Fully booked"),
+    /// ])
+    /// ```
+    pub fn with_reason(self, reason: impl Into<SharedString>) -> Self {
+        Matcher::Reason(Box::new(self), reason.into())
+    }
+
+    /// Returns the reason of the first matched matcher with reason.
+    pub fn reason(&self, date: &NaiveDate) -> Option<SharedString> {
+        match self {
+            Matcher::Reason(matcher, reason) => matcher
+                .matched(date)
+                .then(|| matcher.reason(date).unwrap_or_else(|| reason.clone())),
+            Matcher::Any(matchers) => matchers
+                .iter()
+                .filter(|matcher| matcher.matched(date))
+                .find_map(|matcher| matcher.reason(date)),
+            Matcher::All(matchers) => {
+                if !self.matched(date) {
+                    return None;
+                }
+                matchers.iter().find_map(|matcher| matcher.reason(date))
+            }
+            _ => None,
+        }
+    }
+
     fn matched(&self, date: &NaiveDate) -> bool {
         match self {
             Matcher::DayOfWeek(days) => days.contains(&date.weekday().num_days_from_sunday()),
@@ -209,6 +513,37 @@ impl Matcher {
                 let to_check = range.to.map_or(false, |to| date > &to);
                 !from_check && !to_check
             }
+            Matcher::Any(matchers) => matchers.iter().any(|matcher| matcher.matched(date)),
+            Matcher::All(matchers) => matchers.iter().all(|matcher| matcher.matched(date)),
+            Matcher::Not(matcher) => !matcher.matched(date),
+            Matcher::Reason(matcher, _) => matcher.matched(date),
+        }
+    }
+
+    /// Returns the first and the last days that are not matched by the interval matchers,
+    /// e.g. the min and max dates, `None` if unbounded.
+    ///
+    /// The other matchers are not considered, they don't bound the days.
+    fn bounds(&self) -> (Option<NaiveDate>, Option<NaiveDate>) {
+        match self {
+            Matcher::Interval(interval) => (interval.before, interval.after),
+            Matcher::Not(matcher) => match matcher.as_ref() {
+                Matcher::Range(range) => (range.from, range.to),
+                _ => (None, None),
+            },
+            Matcher::Reason(matcher, _) => matcher.bounds(),
+            // The days matched by any of the matchers are matched, so take the narrowest.
+            Matcher::Any(matchers) => matchers.iter().map(|matcher| matcher.bounds()).fold(
+                (None, None),
+                |(first, last), (other_first, other_last)| {
+                    let last = match (last, other_last) {
+                        (Some(last), Some(other_last)) => Some(other_last.min(last)),
+                        (last, other_last) => last.or(other_last),
+                    };
+                    (first.max(other_first), last)
+                },
+            ),
+            _ => (None, None),
         }
     }
 
@@ -216,14 +551,44 @@ impl Matcher {
         match date {
             Date::Single(Some(date)) => self.matched(date),
             Date::Range(Some(start), Some(end)) => self.matched(start) || self.matched(end),
+            Date::Multiple(dates) => dates.iter().any(|date| self.matched(date)),
             _ => false,
         }
     }
 }
 
+/// The focus handles of the header buttons, to cycle them by the `tab` key.
+struct HeaderFocusHandles {
+    prev_year: FocusHandle,
+    prev: FocusHandle,
+    month: FocusHandle,
+    year: FocusHandle,
+    next: FocusHandle,
+    next_year: FocusHandle,
+}
+
+impl HeaderFocusHandles {
+    fn new(cx: &mut App) -> Self {
+        Self {
+            prev_year: cx.focus_handle(),
+            prev: cx.focus_handle(),
+            month: cx.focus_handle(),
+            year: cx.focus_handle(),
+            next: cx.focus_handle(),
+            next_year: cx.focus_handle(),
+        }
+    }
+}
+
 pub struct Calendar {
     focus_handle: FocusHandle,
+    header_focus_handles: HeaderFocusHandles,
     size: Size,
+    density: Density,
+    /// The date format of the day labels.
+    label_format: SharedString,
+    fixed_rows: bool,
+    selection_shape: SelectionShape,
     date: Date,
     view_mode: ViewMode,
     current_year: i32,
@@ -232,8 +597,37 @@ pub struct Calendar {
     year_page: i32,
     /// Number of the months view to show.
     number_of_months: usize,
+    /// Navigate the months view separately, see [`Calendar::independent_panes`].
+    independent_panes: bool,
+    /// The month offset of each months view from the first one, for the `independent_panes`.
+    pane_offsets: Vec<usize>,
+    /// The direction to stack the months view.
+    orientation: Axis,
+    scroll_handle: ScrollHandle,
     today: NaiveDate,
+    highlight_today: bool,
+    highlight_weekends: bool,
     disabled: Option<Matcher>,
+    /// The max number of the days to select in the multiple mode, `None` for no limit.
+    max_selections: Option<usize>,
+    granularity: Granularity,
+    /// The first day of the hovered week, to preview the week in `Granularity::Week`.
+    hovered_week: Option<NaiveDate>,
+    /// The endpoint to update by the next day selection in range mode, `None` to select a new range.
+    range_endpoint: Option<RangeEndpoint>,
+    /// The anchor and the cursor of the range extending by the keyboard, to preview before confirm.
+    range_preview: Option<(NaiveDate, NaiveDate)>,
+    /// The day moved to by typing the day number, to select by Enter.
+    active_day: Option<NaiveDate>,
+    /// The typed text to jump to the year, month or day, and the time of the last typed key.
+    typeahead: String,
+    typeahead_at: Option<Instant>,
+    presets: Option<Vec<DateRangePreset>>,
+    loading: bool,
+    quick_jump: bool,
+    /// The input to type the date to jump, created at the first render with `quick_jump`.
+    quick_jump_input: Option<Entity<TextInput>>,
+    _subscriptions: Vec<Subscription>,
 }
 
 impl Calendar {
@@ -241,7 +635,12 @@ impl Calendar {
         let today = Local::now().naive_local().date();
         Self {
             focus_handle: cx.focus_handle(),
+            header_focus_handles: HeaderFocusHandles::new(cx),
             size: Size::default(),
+            density: Density::default(),
+            label_format: "%Y/%m/%d".into(),
+            fixed_rows: true,
+            selection_shape: SelectionShape::default(),
             view_mode: ViewMode::Day,
             date: Date::Single(None),
             current_month: today.month() as u8,
@@ -249,8 +648,27 @@ impl Calendar {
             years: vec![],
             year_page: 0,
             number_of_months: 1,
+            independent_panes: false,
+            pane_offsets: vec![0],
+            orientation: Axis::Horizontal,
+            scroll_handle: ScrollHandle::new(),
             today,
+            highlight_today: true,
+            highlight_weekends: false,
             disabled: None,
+            max_selections: None,
+            granularity: Granularity::default(),
+            hovered_week: None,
+            range_endpoint: None,
+            range_preview: None,
+            active_day: None,
+            typeahead: String::new(),
+            typeahead_at: None,
+            presets: None,
+            loading: false,
+            quick_jump: false,
+            quick_jump_input: None,
+            _subscriptions: vec![],
         }
         .year_range((today.year() - 50, today.year() + 50))
     }
@@ -260,43 +678,200 @@ impl Calendar {
     /// When you set a range date, the mode will be automatically set to `Mode::Range`.
     pub fn set_date(&mut self, date: impl Into<Date>, _: &mut Window, cx: &mut Context<Self>) {
         let date = date.into();
-
-        let invalid = self
-            .disabled
-            .as_ref()
-            .map_or(false, |disabled| disabled.date_matched(&date));
-
-        if invalid {
+        if self.is_date_disabled(&date) {
             return;
         }
 
         self.date = date;
-        match self.date {
-            Date::Single(Some(date)) => {
-                self.current_month = date.month() as u8;
-                self.current_year = date.year();
-            }
-            Date::Range(Some(start), _) => {
-                self.current_month = start.month() as u8;
-                self.current_year = start.year();
+        self.range_preview = None;
+        self.active_day = None;
+        if let Some(date) = self.date.start() {
+            match self.month_offset(&date) {
+                // In vertical, keep the months and scroll to the month if it is already visible.
+                Some(offset) if self.orientation.is_vertical() => {
+                    self.scroll_handle.scroll_to_item(offset);
+                }
+                _ => {
+                    self.current_month = date.month() as u8;
+                    self.current_year = date.year();
+                    self.scroll_handle.scroll_to_item(0);
+                }
             }
-            _ => {}
         }
 
         cx.notify()
     }
 
+    /// Returns the index of the month view that contains the date, if visible.
+    fn month_offset(&self, date: &NaiveDate) -> Option<usize> {
+        (0..self.number_of_months).find(|&pane| {
+            let (year, month) = self.offset_year_month(self.pane_offset(pane));
+            date.year() == year && date.month() == month
+        })
+    }
+
+    /// Returns the month offset of the month view from the first one.
+    fn pane_offset(&self, pane: usize) -> usize {
+        if self.independent_panes {
+            self.pane_offsets.get(pane).copied().unwrap_or(pane)
+        } else {
+            pane
+        }
+    }
+
+    fn reset_pane_offsets(&mut self) {
+        self.pane_offsets = (0..self.number_of_months).collect();
+    }
+
+    /// Returns true if the month view can move by the delta months,
+    /// without overlapping or passing its neighbour views.
+    fn can_navigate_pane(&self, pane: usize, delta: i32) -> bool {
+        let offset = self.pane_offset(pane) as i32 + delta;
+        let after_prev = pane == 0 || offset > self.pane_offset(pane - 1) as i32;
+        let before_next =
+            pane + 1 >= self.number_of_months || offset < self.pane_offset(pane + 1) as i32;
+        after_prev && before_next
+    }
+
+    /// Move the month view by the delta months, for the `independent_panes`.
+    fn navigate_pane(&mut self, pane: usize, delta: i32, cx: &mut Context<Self>) {
+        if !self.can_navigate_pane(pane, delta) {
+            return;
+        }
+        if self.pane_offsets.len() != self.number_of_months {
+            self.reset_pane_offsets();
+        }
+
+        if pane == 0 {
+            // The offsets are relative to the first view, keep the other views in place.
+            for offset in self.pane_offsets.iter_mut().skip(1) {
+                *offset = (*offset as i32 - delta) as usize;
+            }
+            let months = self.current_year * 12 + self.current_month as i32 - 1 + delta;
+            self.navigate(months.div_euclid(12), months.rem_euclid(12) as u8 + 1, cx);
+        } else {
+            self.pane_offsets[pane] = (self.pane_offsets[pane] as i32 + delta) as usize;
+            cx.notify();
+        }
+    }
+
     /// Get the date of the calendar.
     pub fn date(&self) -> Date {
-        self.date
+        self.date.clone()
+    }
+
+    /// Returns true if the date is matched by the disabled matcher.
+    pub fn is_date_disabled(&self, date: &Date) -> bool {
+        self.disabled
+            .as_ref()
+            .map_or(false, |disabled| disabled.date_matched(date))
+    }
+
+    /// Returns the label of the day cell with its state, e.g. `2024/03/15, selected`.
+    ///
+    /// The day is formatted by the [`Calendar::set_label_format`], the label is shown as the
+    /// tooltip of the day cell.
+    pub fn day_label(&self, date: &NaiveDate) -> SharedString {
+        let mut label = date.format(&self.label_format).to_string();
+        if self.date.is_active(date) {
+            label.push_str(&format!(", {}", t!("Calendar.selected")));
+        }
+        if self.is_day_disabled(date) {
+            label.push_str(&format!(", {}", t!("Calendar.disabled")));
+        }
+        label.into()
+    }
+
+    /// Set the date format of the [`Calendar::day_label`], default is `%Y/%m/%d`.
+    pub fn set_label_format(
+        &mut self,
+        format: impl Into<SharedString>,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.label_format = format.into();
+        cx.notify();
+    }
+
+    /// Set true to mark today with a ring and bold text, default is `true`.
+    pub fn highlight_today(mut self, highlight_today: bool) -> Self {
+        self.highlight_today = highlight_today;
+        self
+    }
+
+    /// Set true to tint the Saturday and Sunday cells with a subtle background, default is false.
+    ///
+    /// The background is beneath the selection, hover and today highlights.
+    pub fn highlight_weekends(mut self, highlight_weekends: bool) -> Self {
+        self.highlight_weekends = highlight_weekends;
+        self
+    }
+
+    /// Set the endpoint to update by the next day selection in range mode,
+    /// the other endpoint is kept, `None` to select a new range.
+    pub fn set_range_endpoint(
+        &mut self,
+        endpoint: Option<RangeEndpoint>,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.range_endpoint = endpoint;
+        cx.notify();
+    }
+
+    /// Get the endpoint to update by the next day selection in range mode.
+    pub fn range_endpoint(&self) -> Option<RangeEndpoint> {
+        self.range_endpoint
     }
 
     /// Set number of months to show, default is 1.
     pub fn number_of_months(mut self, number_of_months: usize) -> Self {
         self.number_of_months = number_of_months;
+        self.reset_pane_offsets();
+        self
+    }
+
+    /// Set true to navigate each months view separately, default is `false`.
+    ///
+    /// Each months view has its own prev and next buttons, and a view can't move before the
+    /// previous one (or after the next one). The header buttons still move all of them together.
+    ///
+    /// This is only for the horizontal `orientation` with more than one `number_of_months`.
+    pub fn independent_panes(mut self, independent_panes: bool) -> Self {
+        self.independent_panes = independent_panes;
+        self.reset_pane_offsets();
         self
     }
 
+    /// Set true to navigate each months view separately.
+    pub fn set_independent_panes(
+        &mut self,
+        independent_panes: bool,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.independent_panes != independent_panes {
+            self.independent_panes = independent_panes;
+            self.reset_pane_offsets();
+            cx.notify();
+        }
+    }
+
+    /// Set the direction to stack the months view, default is `Axis::Horizontal`.
+    ///
+    /// In `Axis::Vertical`, the months view is scrollable and will scroll to the
+    /// selected month, this is useful with a large `number_of_months`.
+    pub fn orientation(mut self, orientation: Axis) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Set the direction to stack the months view.
+    pub fn set_orientation(&mut self, orientation: Axis, _: &mut Window, cx: &mut Context<Self>) {
+        self.orientation = orientation;
+        cx.notify();
+    }
+
     pub fn set_size(&mut self, size: Size, _: &mut Window, cx: &mut Context<Self>) {
         self.size = size;
         cx.notify();
@@ -308,7 +883,10 @@ impl Calendar {
         _: &mut Window,
         cx: &mut Context<Self>,
     ) {
-        self.number_of_months = number_of_months;
+        if self.number_of_months != number_of_months {
+            self.number_of_months = number_of_months;
+            self.reset_pane_offsets();
+        }
         cx.notify();
     }
 
@@ -329,11 +907,222 @@ impl Calendar {
         self
     }
 
-    /// Set the disabled matcher of the calendar.
+    /// Set the granularity of the date to select, default is `Granularity::Day`.
+    pub fn granularity(mut self, granularity: Granularity) -> Self {
+        self.granularity = granularity;
+        self.view_mode = self.base_view_mode();
+        self
+    }
+
+    /// Set the granularity of the date to select.
+    pub fn set_granularity(
+        &mut self,
+        granularity: Granularity,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.granularity = granularity;
+        self.view_mode = self.base_view_mode();
+        cx.notify();
+    }
+
+    /// Returns the view mode to select the date with the granularity.
+    fn base_view_mode(&self) -> ViewMode {
+        match self.granularity {
+            Granularity::Day | Granularity::Week => ViewMode::Day,
+            Granularity::Month => ViewMode::Month,
+            Granularity::Quarter => ViewMode::Quarter,
+            Granularity::Year => ViewMode::Year,
+        }
+    }
+
+    /// Set the matcher of the days to disable when creating the calendar, e.g. the weekends.
+    ///
+    /// Use [`Calendar::set_disabled`] to change it later.
+    pub fn disabled(mut self, disabled: impl Into<Matcher>) -> Self {
+        self.disabled = Some(disabled.into());
+        self
+    }
+
+    /// Set preset dates to display on the left of the calendar.
+    ///
+    /// When a preset is clicked, the date will be selected and emit [`CalendarEvent::Selected`].
+    pub fn presets(mut self, presets: Vec<DateRangePreset>) -> Self {
+        self.presets = Some(presets);
+        self
+    }
+
+    /// Set the loading state of the calendar, default is `false`.
+    ///
+    /// When loading, a spinner is displayed over the calendar and the dates can't be selected,
+    /// e.g. the disabled dates are fetching asynchronously.
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
+    /// Set the density of the day cells, default is `Density::Normal`.
+    pub fn density(mut self, density: Density) -> Self {
+        self.density = density;
+        self
+    }
+
+    /// Set the density of the day cells.
+    pub fn set_density(&mut self, density: Density, _: &mut Window, cx: &mut Context<Self>) {
+        self.density = density;
+        cx.notify();
+    }
+
+    /// Set true to always render 6 weeks in the days view, default is `true`.
+    ///
+    /// This keeps the height of the calendar stable across the months,
+    /// set false to render only the weeks of the month (4 to 6).
+    pub fn fixed_rows(mut self, fixed_rows: bool) -> Self {
+        self.fixed_rows = fixed_rows;
+        self
+    }
+
+    /// Set true to always render 6 weeks in the days view.
+    pub fn set_fixed_rows(&mut self, fixed_rows: bool, _: &mut Window, cx: &mut Context<Self>) {
+        self.fixed_rows = fixed_rows;
+        cx.notify();
+    }
+
+    /// Set the shape of the selected and in-range day cells, default is `SelectionShape::Rounded`.
     ///
-    /// The disabled matcher will be used to disable the days that match the matcher.
-    pub fn set_disabled(&mut self, disabled: Matcher, _: &mut Window, _: &mut Context<Self>) {
+    /// With `Circle` and `Square`, the days of the range are connected as a bar between the endpoints.
+    pub fn selection_shape(mut self, shape: SelectionShape) -> Self {
+        self.selection_shape = shape;
+        self
+    }
+
+    /// Set the shape of the selected and in-range day cells.
+    pub fn set_selection_shape(
+        &mut self,
+        shape: SelectionShape,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.selection_shape = shape;
+        cx.notify();
+    }
+
+    /// Returns the size of the day and week cells, by the size and density.
+    ///
+    /// The compact [`ThemeDensity`] makes the cells one density smaller.
+    fn cell_size(&self, cx: &App) -> Rems {
+        let size = match self.size {
+            Size::Small => 1.75,
+            Size::Large => 2.5,
+            Size::XLarge => 3.,
+            Size::XSmall | Size::Medium | Size::Size(_) => 2.25,
+        };
+        let density = match (self.density, cx.theme().density) {
+            (Density::Comfortable, ThemeDensity::Compact) => Density::Normal,
+            (_, ThemeDensity::Compact) => Density::Compact,
+            (density, ThemeDensity::Cozy) => density,
+        };
+        let delta = match density {
+            Density::Compact => -0.5,
+            Density::Normal => 0.,
+            Density::Comfortable => 0.5,
+        };
+        rems(size + delta)
+    }
+
+    /// Set true to show an input in the day view to type a date to jump, default is false.
+    ///
+    /// The input accepts the dates like `2024-05-01` and the offsets from today like `+3d`, `-1w`,
+    /// `+2m`, `+1y`. The date is selected in single mode, or the month is shown in range mode.
+    pub fn quick_jump(mut self, quick_jump: bool) -> Self {
+        self.quick_jump = quick_jump;
+        self
+    }
+
+    /// Set true to show an input in the day view to type a date to jump.
+    pub fn set_quick_jump(&mut self, quick_jump: bool, _: &mut Window, cx: &mut Context<Self>) {
+        self.quick_jump = quick_jump;
+        cx.notify();
+    }
+
+    /// Jump to the typed date, returns false if the text can't be parsed.
+    fn jump_to_text(&mut self, text: &str, window: &mut Window, cx: &mut Context<Self>) -> bool {
+        let Some(date) = parse_relative_offset(text, self.today)
+            .or_else(|| parse_date(text, &QUICK_JUMP_FORMATS))
+        else {
+            return false;
+        };
+
+        if self.date.is_single()
+            && self.granularity == Granularity::Day
+            && !self.is_date_disabled(&Date::Single(Some(date)))
+        {
+            self.set_date(date, window, cx);
+            cx.emit(CalendarEvent::Selected(self.date()));
+        }
+        self.navigate(date.year(), date.month() as u8, cx);
+        true
+    }
+
+    fn quick_jump_input(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Entity<TextInput> {
+        if let Some(input) = self.quick_jump_input.as_ref() {
+            return input.clone();
+        }
+
+        let input = cx.new(|cx| {
+            TextInput::new(window, cx)
+                .small()
+                .placeholder(t!("Calendar.quick_jump"))
+        });
+        self._subscriptions = vec![cx.subscribe_in(
+            &input,
+            window,
+            |this, input, event: &InputEvent, window, cx| {
+                if let InputEvent::PressEnter { .. } = event {
+                    let text = input.read(cx).text().clone();
+                    if this.jump_to_text(&text, window, cx) {
+                        input.update(cx, |input, cx| input.set_text("", window, cx));
+                    }
+                }
+            },
+        )];
+        self.quick_jump_input = Some(input.clone());
+        input
+    }
+
+    /// Set the loading state of the calendar.
+    pub fn set_loading(&mut self, loading: bool, _: &mut Window, cx: &mut Context<Self>) {
+        self.loading = loading;
+        cx.notify();
+    }
+
+    /// Returns true if the calendar is loading.
+    pub fn is_loading(&self) -> bool {
+        self.loading
+    }
+
+    /// Replace the matcher of the disabled days, e.g. after the booked days are loaded,
+    /// the calendar is re-rendered with the new disabled days.
+    pub fn set_disabled(&mut self, disabled: Matcher, _: &mut Window, cx: &mut Context<Self>) {
         self.disabled = Some(disabled);
+        cx.notify();
+    }
+
+    /// Set the max number of the days to select in [`Date::Multiple`], `None` for no limit.
+    ///
+    /// Once reached, the other days are disabled until a selected day is deselected.
+    pub fn set_max_selections(
+        &mut self,
+        max_selections: Option<usize>,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.max_selections = max_selections;
+        cx.notify();
     }
 
     /// Get year and month by offset month.
@@ -349,69 +1138,409 @@ impl Calendar {
             year += 1;
         }
 
-        (year, month as u32)
+        (year, month as u32)
+    }
+
+    /// Returns the weeks of days of each month pane to render on calendar.
+    fn days(&self) -> Vec<Vec<Vec<NaiveDate>>> {
+        (0..self.number_of_months)
+            .map(|pane| {
+                let (year, month) = self.offset_year_month(self.pane_offset(pane));
+                days_in_month(year, month, self.fixed_rows)
+            })
+            .collect()
+    }
+
+    fn has_prev_year_page(&self) -> bool {
+        self.year_page > 0
+    }
+
+    fn has_next_year_page(&self) -> bool {
+        self.year_page < self.years.len() as i32 - 1
+    }
+
+    fn prev_year_page(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        if !self.has_prev_year_page() {
+            return;
+        }
+
+        self.year_page -= 1;
+        cx.notify()
+    }
+
+    fn next_year_page(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        if !self.has_next_year_page() {
+            return;
+        }
+
+        self.year_page += 1;
+        cx.notify()
+    }
+
+    fn prev_year(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        self.jump_year(-1, cx);
+    }
+
+    fn next_year(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        self.jump_year(1, cx);
+    }
+
+    /// Move the visible month by whole years, see [`Calendar::clamp_month`].
+    fn jump_year(&mut self, delta: i32, cx: &mut Context<Self>) {
+        let (year, month) = self.clamp_month(self.current_year + delta, self.current_month);
+        self.navigate(year, month, cx);
+    }
+
+    /// Clamp the month to navigate into the `year_range`, and the months of the min and max
+    /// dates by the disabled matcher, e.g. `Matcher::interval`.
+    fn clamp_month(&self, year: i32, month: u8) -> (i32, u8) {
+        let months = |year: i32, month: u32| year * 12 + month as i32 - 1;
+        let (first_day, last_day) = self
+            .disabled
+            .as_ref()
+            .map_or((None, None), |disabled| disabled.bounds());
+        let first_year = self.years.first().and_then(|years| years.first());
+        let last_year = self.years.last().and_then(|years| years.last());
+
+        let lower = [
+            first_year.map(|year| months(*year, 1)),
+            first_day.map(|day| months(day.year(), day.month())),
+        ]
+        .into_iter()
+        .flatten()
+        .max();
+        let upper = [
+            last_year.map(|year| months(*year, 12)),
+            last_day.map(|day| months(day.year(), day.month())),
+        ]
+        .into_iter()
+        .flatten()
+        .min();
+
+        let mut target = months(year, month as u32);
+        if let Some(upper) = upper {
+            target = target.min(upper);
+        }
+        if let Some(lower) = lower {
+            target = target.max(lower);
+        }
+        (target.div_euclid(12), target.rem_euclid(12) as u8 + 1)
+    }
+
+    pub(super) fn on_action_prev_year(
+        &mut self,
+        _: &PrevYear,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.view_mode.is_year() {
+            return;
+        }
+
+        self.jump_year(-1, cx);
+    }
+
+    pub(super) fn on_action_next_year(
+        &mut self,
+        _: &NextYear,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.view_mode.is_year() {
+            return;
+        }
+
+        self.jump_year(1, cx);
+    }
+
+    pub(super) fn on_action_prev_month(
+        &mut self,
+        _: &PrevMonth,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.view_mode.is_day() {
+            self.step_prev_month(cx);
+        }
+    }
+
+    pub(super) fn on_action_next_month(
+        &mut self,
+        _: &NextMonth,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.view_mode.is_day() {
+            self.step_next_month(cx);
+        }
+    }
+
+    pub(super) fn on_action_extend_prev_day(
+        &mut self,
+        _: &ExtendPrevDay,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        // The days are reversed in right-to-left, the left arrow moves to the next day.
+        let days = if crate::direction(cx).is_rtl() { 1 } else { -1 };
+        self.extend_range(days, cx);
+    }
+
+    pub(super) fn on_action_extend_next_day(
+        &mut self,
+        _: &ExtendNextDay,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let days = if crate::direction(cx).is_rtl() { -1 } else { 1 };
+        self.extend_range(days, cx);
+    }
+
+    pub(super) fn on_action_extend_prev_week(
+        &mut self,
+        _: &ExtendPrevWeek,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.extend_range(-7, cx);
+    }
+
+    pub(super) fn on_action_extend_next_week(
+        &mut self,
+        _: &ExtendNextWeek,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.extend_range(7, cx);
+    }
+
+    /// Commit the range extended by the keyboard, and emit the [`CalendarEvent::Selected`].
+    pub(super) fn on_action_confirm_range(
+        &mut self,
+        _: &ConfirmRange,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.confirm_range_preview(window, cx) {
+            return;
+        }
+
+        match self.active_day.take() {
+            Some(date) if self.view_mode.is_day() && !self.is_day_disabled(&date) => {
+                self.select_day(date, window, cx);
+            }
+            _ => cx.propagate(),
+        }
+    }
+
+    /// Commit the range preview if any, returns false if there is no range preview.
+    pub(super) fn confirm_range_preview(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> bool {
+        let Some((anchor, cursor)) = self.range_preview.take() else {
+            return false;
+        };
+
+        let date = Date::Range(Some(anchor.min(cursor)), Some(anchor.max(cursor)));
+        self.set_date(date.clone(), window, cx);
+        if self.date == date {
+            cx.emit(CalendarEvent::Selected(date));
+        }
+        cx.notify();
+        true
+    }
+
+    /// Move the cursor of the range preview by days from the anchor, like the text selection.
+    ///
+    /// The anchor is the start of the range, or today if visible, or the first day of the month.
+    fn extend_range(&mut self, days: i64, cx: &mut Context<Self>) {
+        if !self.date.is_range()
+            || self.granularity != Granularity::Day
+            || !self.view_mode.is_day()
+            || self.loading
+        {
+            cx.propagate();
+            return;
+        }
+
+        let (anchor, cursor) = self.range_preview.unwrap_or_else(|| {
+            let anchor = self.date.start().unwrap_or_else(|| {
+                if self.month_offset(&self.today).is_some() {
+                    self.today
+                } else {
+                    NaiveDate::from_ymd_opt(self.current_year, self.current_month as u32, 1)
+                        .unwrap_or(self.today)
+                }
+            });
+            (anchor, self.date.end().unwrap_or(anchor))
+        });
+        let Some(cursor) = cursor.checked_add_signed(chrono::Duration::days(days)) else {
+            return;
+        };
+
+        // Follow the cursor across the months, keep it in the first or the last months view.
+        if self.month_offset(&cursor).is_none() {
+            let (year, month) = self.visible_month();
+            let months = cursor.year() * 12 + cursor.month0() as i32;
+            let first = if months < year * 12 + month as i32 - 1 {
+                months
+            } else {
+                months - self.pane_offset(self.number_of_months.saturating_sub(1)) as i32
+            };
+            self.navigate(first.div_euclid(12), first.rem_euclid(12) as u8 + 1, cx);
+        }
+
+        self.range_preview = Some((anchor, cursor));
+        cx.notify();
+    }
+
+    fn prev_month(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        self.step_prev_month(cx);
+    }
+
+    fn next_month(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+        self.step_next_month(cx);
     }
 
-    /// Returns the days of the month in a 2D vector to render on calendar.
-    fn days(&self) -> Vec<Vec<NaiveDate>> {
-        (0..self.number_of_months)
-            .flat_map(|offset| {
-                days_in_month(self.current_year, self.current_month as u32 + offset as u32)
-            })
-            .collect()
+    fn step_prev_month(&mut self, cx: &mut Context<Self>) {
+        let (year, month) = if self.current_month == 1 {
+            self.clamp_month(self.current_year - 1, 12)
+        } else {
+            self.clamp_month(self.current_year, self.current_month - 1)
+        };
+        self.navigate(year, month, cx);
     }
 
-    fn has_prev_year_page(&self) -> bool {
-        self.year_page > 0
+    fn step_next_month(&mut self, cx: &mut Context<Self>) {
+        let (year, month) = if self.current_month == 12 {
+            self.clamp_month(self.current_year + 1, 1)
+        } else {
+            self.clamp_month(self.current_year, self.current_month + 1)
+        };
+        self.navigate(year, month, cx);
     }
 
-    fn has_next_year_page(&self) -> bool {
-        self.year_page < self.years.len() as i32 - 1
+    /// Jump to the year matching the typed digits in the years view (e.g. `199` to 1990),
+    /// or the month matching the typed letters in the months view,
+    /// or move the active day to the typed day number of the visible month in the days view.
+    ///
+    /// Returns true if the key is handled.
+    pub(super) fn typeahead(&mut self, key: &str, window: &Window, cx: &mut Context<Self>) -> bool {
+        // The keys are typed into the quick jump input.
+        if let Some(input) = self.quick_jump_input.as_ref() {
+            if input.focus_handle(cx).is_focused(window) {
+                return false;
+            }
+        }
+
+        let mut chars = key.chars();
+        let (Some(c), None) = (chars.next(), chars.next()) else {
+            return false;
+        };
+        let handled = if self.view_mode.is_day() {
+            c.is_ascii_digit() && !self.loading
+        } else {
+            (self.view_mode.is_year() || self.view_mode.is_month()) && c.is_alphanumeric()
+        };
+        if !handled {
+            return false;
+        }
+
+        let now = Instant::now();
+        if self
+            .typeahead_at
+            .map_or(true, |at| now.duration_since(at) > TYPEAHEAD_TIMEOUT)
+        {
+            self.typeahead.clear();
+        }
+        self.typeahead_at = Some(now);
+        self.typeahead.push(c);
+
+        if self.view_mode.is_year() {
+            let years = self.years.iter().flatten().copied().collect::<Vec<_>>();
+            let labels = years.iter().map(|y| y.to_string()).collect::<Vec<_>>();
+            if let Some(ix) = typeahead_match(&labels, &self.typeahead) {
+                let year = years[ix];
+                self.year_page = self
+                    .years
+                    .iter()
+                    .position(|page| page.contains(&year))
+                    .unwrap_or(0) as i32;
+                self.navigate(year, self.current_month, cx);
+            }
+        } else if self.view_mode.is_month() {
+            if let Some(ix) = typeahead_match(&self.months(), &self.typeahead) {
+                self.navigate(self.current_year, ix as u8 + 1, cx);
+            }
+        } else {
+            // Start a new day number after two digits.
+            if self.typeahead.len() > 2 {
+                self.typeahead = c.to_string();
+            }
+            let (year, month) = (self.current_year, self.current_month as u32);
+            self.active_day = typeahead_day(&self.typeahead, year, month)
+                .and_then(|day| NaiveDate::from_ymd_opt(year, month, day));
+        }
+
+        cx.notify();
+        true
     }
 
-    fn prev_year_page(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
-        if !self.has_prev_year_page() {
+    fn on_key_down(&mut self, event: &KeyDownEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let modifiers = event.keystroke.modifiers;
+        if modifiers.control || modifiers.alt || modifiers.platform {
             return;
         }
 
-        self.year_page -= 1;
-        cx.notify()
+        if self.typeahead(&event.keystroke.key, window, cx) {
+            cx.stop_propagation();
+        }
     }
 
-    fn next_year_page(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
-        if !self.has_next_year_page() {
+    /// Change the visible month by the user navigation, emit [`CalendarEvent::MonthChanged`] if changed.
+    fn navigate(&mut self, year: i32, month: u8, cx: &mut Context<Self>) {
+        if self.current_year == year && self.current_month == month {
             return;
         }
 
-        self.year_page += 1;
+        self.current_year = year;
+        self.current_month = month;
+        // The typed day belongs to the previous month, the typeahead of the years and months
+        // navigates by itself, keep it to type more.
+        self.active_day = None;
+        if self.view_mode.is_day() {
+            self.typeahead.clear();
+        }
+        cx.emit(CalendarEvent::MonthChanged {
+            year,
+            month: month as u32,
+        });
         cx.notify()
     }
 
-    fn prev_month(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
-        self.current_month = if self.current_month == 1 {
-            12
-        } else {
-            self.current_month - 1
-        };
-        self.current_year = if self.current_month == 12 {
-            self.current_year - 1
-        } else {
-            self.current_year
-        };
-        cx.notify()
+    /// Get the visible year and month (1..=12) of the first months view.
+    pub fn visible_month(&self) -> (i32, u32) {
+        (self.current_year, self.current_month as u32)
     }
 
-    fn next_month(&mut self, _: &ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
-        self.current_month = if self.current_month == 12 {
-            1
-        } else {
-            self.current_month + 1
-        };
-        self.current_year = if self.current_month == 1 {
-            self.current_year + 1
-        } else {
-            self.current_year
-        };
+    /// Set the visible year and month (1..=12) of the first months view.
+    ///
+    /// This will not emit [`CalendarEvent::MonthChanged`], the invalid month is ignored.
+    pub fn set_visible_month(
+        &mut self,
+        year: i32,
+        month: u32,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if !(1..=12).contains(&month) {
+            return;
+        }
+
+        self.current_year = year;
+        self.current_month = month as u8;
         cx.notify()
     }
 
@@ -442,14 +1571,18 @@ impl Calendar {
         cx: &mut Context<Self>,
     ) -> impl IntoElement {
         h_flex()
+            .size(self.cell_size(cx))
             .map(|this| match self.size {
-                Size::Small => this.size_7().rounded(cx.theme().radius / 2.0),
-                Size::Large => this.size_10().rounded(cx.theme().radius),
-                _ => this.size_9().rounded(cx.theme().radius),
+                Size::Small => this.rounded(cx.theme().radius / 2.0),
+                Size::XLarge => this.rounded(cx.theme().radius * 1.5),
+                _ => this.rounded(cx.theme().radius),
             })
             .justify_center()
             .text_color(cx.theme().muted_foreground)
-            .text_sm()
+            .map(|this| match self.size {
+                Size::XLarge => this.text_base(),
+                _ => this.text_sm(),
+            })
             .child(week.into())
     }
 
@@ -467,10 +1600,11 @@ impl Calendar {
     ) -> impl IntoElement + Styled + StatefulInteractiveElement {
         h_flex()
             .id(id.into())
+            .size(self.cell_size(cx))
             .map(|this| match self.size {
-                Size::Small => this.size_7().rounded(cx.theme().radius),
-                Size::Large => this.size_10().rounded(cx.theme().radius * 2.),
-                _ => this.size_9().rounded(cx.theme().radius * 2.),
+                Size::Small => this.rounded(cx.theme().radius),
+                Size::XLarge => this.rounded(cx.theme().radius * 2.5),
+                _ => this.rounded(cx.theme().radius * 2.),
             })
             .justify_center()
             .when(muted, |this| {
@@ -480,6 +1614,10 @@ impl Calendar {
                     cx.theme().muted_foreground
                 })
             })
+            // Not only rely on the color to distinguish the disabled dates in high-contrast.
+            .when(disabled && cx.theme().high_contrast, |this| {
+                this.line_through()
+            })
             .when(secondary_active, |this| {
                 this.bg(if muted {
                     cx.theme().accent.opacity(0.5)
@@ -511,62 +1649,198 @@ impl Calendar {
         let (_, month) = self.offset_year_month(offset_month);
         let day = d.day();
         let is_current_month = d.month() == month;
-        let is_active = self.date.is_active(d) && is_current_month;
-        let is_in_range = self.date.is_in_range(d);
+        // Show the range extending by the keyboard instead of the date.
+        let preview = self.range_preview.map(|(anchor, cursor)| {
+            Date::Range(Some(anchor.min(cursor)), Some(anchor.max(cursor)))
+        });
+        let shown = preview.as_ref().unwrap_or(&self.date);
+        let is_active = shown.is_active(d) && is_current_month;
+        let is_in_range = shown.is_in_range(d);
 
         let date = *d;
         let is_today = *d == self.today;
-        let disabled = self
-            .disabled
-            .as_ref()
-            .map_or(false, |disabled| disabled.matched(&date));
+        let is_typed_day = self.active_day == Some(date) && is_current_month;
+        // Once the max selections reached, only the selected days can be clicked to deselect.
+        let capped = !shown.is_active(d)
+            && self
+                .max_selections
+                .map_or(false, |max| shown.is_multiple() && shown.count() >= max);
+        let disabled = self.is_day_disabled(&date) || capped;
+        let disabled_reason = disabled
+            .then(|| self.disabled.as_ref().and_then(|m| m.reason(&date)))
+            .flatten();
+        // The disabled reason follows the label, e.g. `2024/03/15, disabled, Holiday`.
+        let label = match disabled_reason {
+            Some(reason) => format!("{}, {}", self.day_label(&date), reason).into(),
+            None => self.day_label(&date),
+        };
+        let week = if self.granularity == Granularity::Week {
+            week_range(d)
+        } else {
+            None
+        };
+        let is_in_hovered_week = week.map_or(false, |(start, _)| {
+            self.hovered_week == Some(start) && is_current_month
+        });
+        // The range endpoints are flattened on the inner side to connect the bar.
+        let shape = self.selection_shape;
+        let rtl = crate::direction(cx).is_rtl();
+        let (bar_start, bar_end) = match (shape, shown) {
+            (SelectionShape::Rounded, _) => (false, false),
+            (_, Date::Range(Some(start), Some(end))) if start < end && is_active => {
+                (date == *start, date == *end)
+            }
+            _ => (false, false),
+        };
+        let is_weekend = matches!(d.weekday(), Weekday::Sat | Weekday::Sun);
+        let tint_weekend = self.highlight_weekends
+            && is_weekend
+            && is_current_month
+            && !is_active
+            && !is_in_range
+            && !is_in_hovered_week;
 
         self.item_button(
             d.ordinal() as usize,
             day.to_string(),
             is_active,
-            is_in_range,
+            is_in_range || is_in_hovered_week,
             !is_current_month || disabled,
             disabled,
             window,
             cx,
         )
-        .when(is_today && !is_active, |this| {
-            this.border_1().border_color(cx.theme().border)
-        }) // Add border for today
+        .map(|this| match shape {
+            SelectionShape::Rounded => this,
+            SelectionShape::Circle => this.rounded_full(),
+            SelectionShape::Square => this.rounded_none(),
+        })
+        .when(
+            shape != SelectionShape::Rounded && is_in_range && !is_active,
+            |this| this.rounded_none(),
+        )
+        .when(bar_start, |this| {
+            if rtl {
+                this.rounded_l(px(0.))
+            } else {
+                this.rounded_r(px(0.))
+            }
+        })
+        .when(bar_end, |this| {
+            if rtl {
+                this.rounded_r(px(0.))
+            } else {
+                this.rounded_l(px(0.))
+            }
+        })
+        .when(tint_weekend, |this| this.bg(cx.theme().muted))
+        .tooltip(move |window, cx| Tooltip::new(label.clone()).build(window, cx))
+        .when(self.highlight_today && is_today && !is_active, |this| {
+            if disabled {
+                this.border_1().border_color(cx.theme().border)
+            } else {
+                this.border_1()
+                    .border_color(cx.theme().ring)
+                    .font_semibold()
+            }
+        })
+        .when_some(week, |this, (start, _)| {
+            this.on_hover(cx.listener(move |view, hovered: &bool, _, cx| {
+                if *hovered {
+                    view.hovered_week = Some(start);
+                } else if view.hovered_week == Some(start) {
+                    view.hovered_week = None;
+                }
+                cx.notify();
+            }))
+        })
+        // Not the same as the today ring, to tell them apart.
+        .when(is_typed_day, |this| {
+            this.border_2()
+                .border_color(cx.theme().ring)
+                .bg(cx.theme().accent)
+        })
         .when(!disabled, |this| {
             this.on_click(cx.listener(move |view, _: &ClickEvent, window, cx| {
-                if view.date.is_single() {
-                    view.set_date(date, window, cx);
-                    cx.emit(CalendarEvent::Selected(view.date()));
-                } else {
-                    let start = view.date.start();
-                    let end = view.date.end();
-
-                    if start.is_none() && end.is_none() {
-                        view.set_date(Date::Range(Some(date), None), window, cx);
-                    } else if start.is_some() && end.is_none() {
-                        if date < start.unwrap() {
-                            view.set_date(Date::Range(Some(date), None), window, cx);
-                        } else {
-                            view.set_date(
-                                Date::Range(Some(start.unwrap()), Some(date)),
-                                window,
-                                cx,
-                            );
-                        }
-                    } else {
-                        view.set_date(Date::Range(Some(date), None), window, cx);
-                    }
+                if view.loading {
+                    return;
+                }
 
-                    if view.date.is_complete() {
-                        cx.emit(CalendarEvent::Selected(view.date()));
-                    }
+                // Keep the clicked month visible in vertical, the `set_date` may scroll to the start.
+                if view.orientation.is_vertical() {
+                    view.scroll_handle.scroll_to_item(offset_month);
                 }
+
+                view.select_day(date, window, cx);
             }))
         })
     }
 
+    fn is_day_disabled(&self, date: &NaiveDate) -> bool {
+        self.disabled
+            .as_ref()
+            .map_or(false, |disabled| disabled.matched(date))
+    }
+
+    /// Select the day by click or Enter, and emit [`CalendarEvent::Selected`] if complete.
+    fn select_day(&mut self, date: NaiveDate, window: &mut Window, cx: &mut Context<Self>) {
+        let week = if self.granularity == Granularity::Week {
+            week_range(&date)
+        } else {
+            None
+        };
+
+        if self.date.is_multiple() {
+            // Not `set_date`, to keep the month view where the day is clicked.
+            if self.date.toggle(date, self.max_selections) {
+                self.active_day = None;
+                cx.emit(CalendarEvent::Selected(self.date()));
+                cx.notify();
+            }
+        } else if let Some((start, end)) = week {
+            self.set_date(Date::Range(Some(start), Some(end)), window, cx);
+            if self.date.is_complete() {
+                cx.emit(CalendarEvent::Selected(self.date()));
+            }
+        } else if self.date.is_single() {
+            self.set_date(date, window, cx);
+            cx.emit(CalendarEvent::Selected(self.date()));
+        } else if let Some(endpoint) = self.range_endpoint.take() {
+            let (start, end) = match endpoint {
+                RangeEndpoint::Start => (Some(date), self.date.end()),
+                RangeEndpoint::End => (self.date.start(), Some(date)),
+            };
+            let date = match (start, end) {
+                (Some(start), Some(end)) if start > end => Date::Range(Some(end), Some(start)),
+                (start, end) => Date::Range(start, end),
+            };
+            self.set_date(date, window, cx);
+
+            if self.date.is_complete() {
+                cx.emit(CalendarEvent::Selected(self.date()));
+            }
+        } else {
+            let start = self.date.start();
+            let end = self.date.end();
+
+            if start.is_none() && end.is_none() {
+                self.set_date(Date::Range(Some(date), None), window, cx);
+            } else if start.is_some() && end.is_none() {
+                if date < start.unwrap() {
+                    self.set_date(Date::Range(Some(date), None), window, cx);
+                } else {
+                    self.set_date(Date::Range(Some(start.unwrap()), Some(date)), window, cx);
+                }
+            } else {
+                self.set_date(Date::Range(Some(date), None), window, cx);
+            }
+
+            if self.date.is_complete() {
+                cx.emit(CalendarEvent::Selected(self.date()));
+            }
+        }
+    }
+
     fn set_view_mode(&mut self, mode: ViewMode, _: &mut Window, cx: &mut Context<Self>) {
         self.view_mode = mode;
         cx.notify();
@@ -592,29 +1866,116 @@ impl Calendar {
         .collect()
     }
 
+    /// Returns the focus handles of the enabled header buttons in the render order,
+    /// followed by the focus handle of the days.
+    pub(crate) fn cycle_focus_handles(&self) -> Vec<FocusHandle> {
+        let pick_by_year = matches!(self.granularity, Granularity::Month | Granularity::Quarter);
+        let disabled = self.view_mode.is_month() && !pick_by_year;
+        let is_day = self.view_mode.is_day();
+        let is_year = self.view_mode.is_year();
+        let multiple_months = self.number_of_months > 1;
+        let handles = &self.header_focus_handles;
+
+        [
+            (is_day, &handles.prev_year),
+            (
+                !disabled && !(is_year && !self.has_prev_year_page()),
+                &handles.prev,
+            ),
+            (
+                !multiple_months
+                    && matches!(self.granularity, Granularity::Day | Granularity::Week),
+                &handles.month,
+            ),
+            (!multiple_months, &handles.year),
+            (
+                !disabled && !(is_year && !self.has_next_year_page()),
+                &handles.next,
+            ),
+            (is_day, &handles.next_year),
+            (true, &self.focus_handle),
+        ]
+        .into_iter()
+        .filter(|(rendered, _)| *rendered)
+        .map(|(_, handle)| handle.clone())
+        .collect()
+    }
+
     fn render_header(&self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         let current_year = self.current_year;
-        let disabled = self.view_mode.is_month();
+        // Select the month or quarter, the prev, next buttons to change the year.
+        let pick_by_year = matches!(self.granularity, Granularity::Month | Granularity::Quarter);
+        let year_nav = pick_by_year && (self.view_mode.is_month() || self.view_mode.is_quarter());
+        let disabled = self.view_mode.is_month() && !pick_by_year;
         let multiple_months = self.number_of_months > 1;
+        let vertical = self.orientation.is_vertical();
+        let rtl = crate::direction(cx).is_rtl();
+        let (prev_icon, next_icon, prev_year_icon, next_year_icon) = if rtl {
+            (
+                IconName::ArrowRight,
+                IconName::ArrowLeft,
+                IconName::ChevronsRight,
+                IconName::ChevronsLeft,
+            )
+        } else {
+            (
+                IconName::ArrowLeft,
+                IconName::ArrowRight,
+                IconName::ChevronsLeft,
+                IconName::ChevronsRight,
+            )
+        };
         let icon_size = match self.size {
             Size::Small => Size::Small,
             Size::Large => Size::Medium,
+            Size::XLarge => Size::Large,
             _ => Size::Medium,
         };
+        let (prev_label, next_label): (SharedString, SharedString) = if self.view_mode.is_year() {
+            (
+                t!("Calendar.prev_years").into(),
+                t!("Calendar.next_years").into(),
+            )
+        } else if year_nav {
+            (
+                t!("Calendar.prev_year").into(),
+                t!("Calendar.next_year").into(),
+            )
+        } else {
+            (
+                t!("Calendar.prev_month").into(),
+                t!("Calendar.next_month").into(),
+            )
+        };
 
         h_flex()
             .gap_0p5()
             .justify_between()
             .items_center()
+            .when(rtl, |this| this.flex_row_reverse())
+            .when(self.view_mode.is_day(), |this| {
+                this.child(
+                    Button::new("prev-year")
+                        .track_focus(&self.header_focus_handles.prev_year)
+                        .icon(prev_year_icon)
+                        .tooltip(t!("Calendar.prev_year"))
+                        .ghost()
+                        .with_size(icon_size)
+                        .on_click(cx.listener(Self::prev_year)),
+                )
+            })
             .child(
                 Button::new("prev")
-                    .icon(IconName::ArrowLeft)
+                    .track_focus(&self.header_focus_handles.prev)
+                    .icon(prev_icon)
+                    .tooltip(prev_label)
                     .ghost()
                     .disabled(disabled)
                     .with_size(icon_size)
                     .when(self.view_mode.is_day(), |this| {
                         this.on_click(cx.listener(Self::prev_month))
                     })
+                    .when(year_nav, |this| this.on_click(cx.listener(Self::prev_year)))
                     .when(self.view_mode.is_year(), |this| {
                         this.when(!self.has_prev_year_page(), |this| this.disabled(true))
                             .on_click(cx.listener(Self::prev_year_page))
@@ -625,24 +1986,31 @@ impl Calendar {
                     h_flex()
                         .justify_center()
                         .gap_3()
-                        .child(
-                            Button::new("month")
-                                .ghost()
-                                .label(self.month_name(0))
-                                .compact()
-                                .with_size(self.size)
-                                .selected(self.view_mode.is_month())
-                                .on_click(cx.listener(|view, _, window, cx| {
-                                    if view.view_mode.is_month() {
-                                        view.set_view_mode(ViewMode::Day, window, cx);
-                                    } else {
-                                        view.set_view_mode(ViewMode::Month, window, cx);
-                                    }
-                                    cx.notify();
-                                })),
+                        .when(
+                            matches!(self.granularity, Granularity::Day | Granularity::Week),
+                            |this| {
+                                this.child(
+                                    Button::new("month")
+                                        .track_focus(&self.header_focus_handles.month)
+                                        .ghost()
+                                        .label(self.month_name(0))
+                                        .compact()
+                                        .with_size(self.size)
+                                        .selected(self.view_mode.is_month())
+                                        .on_click(cx.listener(|view, _, window, cx| {
+                                            if view.view_mode.is_month() {
+                                                view.set_view_mode(ViewMode::Day, window, cx);
+                                            } else {
+                                                view.set_view_mode(ViewMode::Month, window, cx);
+                                            }
+                                            cx.notify();
+                                        })),
+                                )
+                            },
                         )
                         .child(
                             Button::new("year")
+                                .track_focus(&self.header_focus_handles.year)
                                 .ghost()
                                 .label(current_year.to_string())
                                 .compact()
@@ -650,7 +2018,7 @@ impl Calendar {
                                 .selected(self.view_mode.is_year())
                                 .on_click(cx.listener(|view, _, window, cx| {
                                     if view.view_mode.is_year() {
-                                        view.set_view_mode(ViewMode::Day, window, cx);
+                                        view.set_view_mode(view.base_view_mode(), window, cx);
                                     } else {
                                         view.set_view_mode(ViewMode::Year, window, cx);
                                     }
@@ -659,35 +2027,63 @@ impl Calendar {
                         ),
                 )
             })
-            .when(multiple_months, |this| {
-                this.child(h_flex().flex_1().justify_around().children(
-                    (0..self.number_of_months).map(|n| {
+            // In vertical or independent panes, the month names are displayed in each month view.
+            .when(
+                multiple_months && (vertical || self.independent_panes),
+                |this| this.child(div().flex_1()),
+            )
+            .when(
+                multiple_months && !vertical && !self.independent_panes,
+                |this| {
+                    this.child(
                         h_flex()
-                            .justify_center()
-                            .map(|this| match self.size {
-                                Size::Small => this.gap_2(),
-                                Size::Large => this.gap_4(),
-                                _ => this.gap_3(),
-                            })
-                            .child(self.month_name(n))
-                            .child(current_year.to_string())
-                    }),
-                ))
-            })
+                            .flex_1()
+                            .justify_around()
+                            .when(rtl, |this| this.flex_row_reverse())
+                            .children((0..self.number_of_months).map(|n| {
+                                let (year, _) = self.offset_year_month(n);
+                                h_flex()
+                                    .justify_center()
+                                    .map(|this| match self.size {
+                                        Size::Small => this.gap_2(),
+                                        Size::Large => this.gap_4(),
+                                        Size::XLarge => this.gap_5(),
+                                        _ => this.gap_3(),
+                                    })
+                                    .child(self.month_name(n))
+                                    .child(year.to_string())
+                            })),
+                    )
+                },
+            )
             .child(
                 Button::new("next")
-                    .icon(IconName::ArrowRight)
+                    .track_focus(&self.header_focus_handles.next)
+                    .icon(next_icon)
+                    .tooltip(next_label)
                     .ghost()
                     .disabled(disabled)
                     .with_size(icon_size)
                     .when(self.view_mode.is_day(), |this| {
                         this.on_click(cx.listener(Self::next_month))
                     })
+                    .when(year_nav, |this| this.on_click(cx.listener(Self::next_year)))
                     .when(self.view_mode.is_year(), |this| {
                         this.when(!self.has_next_year_page(), |this| this.disabled(true))
                             .on_click(cx.listener(Self::next_year_page))
                     }),
             )
+            .when(self.view_mode.is_day(), |this| {
+                this.child(
+                    Button::new("next-year")
+                        .track_focus(&self.header_focus_handles.next_year)
+                        .icon(next_year_icon)
+                        .tooltip(t!("Calendar.next_year"))
+                        .ghost()
+                        .with_size(icon_size)
+                        .on_click(cx.listener(Self::next_year)),
+                )
+            })
     }
 
     fn render_days(&self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
@@ -701,35 +2097,115 @@ impl Calendar {
             t!("Calendar.week.6"),
         ];
 
-        h_flex()
+        let vertical = self.orientation.is_vertical() && self.number_of_months > 1;
+        let independent = self.independent_panes && !vertical && self.number_of_months > 1;
+        let rtl = crate::direction(cx).is_rtl();
+        let (prev_icon, next_icon) = if rtl {
+            (IconName::ArrowRight, IconName::ArrowLeft)
+        } else {
+            (IconName::ArrowLeft, IconName::ArrowRight)
+        };
+        // No gap between the cells to connect the range bar.
+        let connected = self.selection_shape != SelectionShape::Rounded;
+
+        div()
+            .id("days")
+            .flex()
             .map(|this| match self.size {
                 Size::Small => this.gap_3().text_sm(),
                 Size::Large => this.gap_5().text_base(),
+                Size::XLarge => this.gap_6().text_lg(),
                 _ => this.gap_4().text_sm(),
             })
-            .justify_between()
-            .children(
-                self.days()
-                    .chunks(5)
-                    .enumerate()
-                    .map(|(offset_month, days)| {
-                        v_flex()
-                            .gap_0p5()
-                            .child(
-                                h_flex().gap_0p5().justify_between().children(
-                                    weeks
-                                        .iter()
-                                        .map(|week| self.render_week(week.clone(), window, cx)),
+            .map(|this| {
+                if vertical {
+                    this.flex_col()
+                        .max_h(rems(24.))
+                        .overflow_y_scroll()
+                        .track_scroll(&self.scroll_handle)
+                } else {
+                    this.flex_row()
+                        .justify_between()
+                        .when(rtl, |this| this.flex_row_reverse())
+                }
+            })
+            .children(self.days().into_iter().enumerate().map(|(pane, days)| {
+                let offset_month = self.pane_offset(pane);
+                let (year, _) = self.offset_year_month(offset_month);
+
+                v_flex()
+                    .gap_0p5()
+                    .when(vertical, |this| {
+                        this.child(
+                            h_flex()
+                                .justify_center()
+                                .gap_2()
+                                .font_medium()
+                                .child(self.month_name(offset_month))
+                                .child(year.to_string()),
+                        )
+                    })
+                    .when(independent, |this| {
+                        this.child(
+                            h_flex()
+                                .justify_between()
+                                .items_center()
+                                .font_medium()
+                                .when(rtl, |this| this.flex_row_reverse())
+                                .child(
+                                    Button::new(("pane-prev", pane))
+                                        .icon(prev_icon.clone())
+                                        .tooltip(t!("Calendar.prev_month"))
+                                        .ghost()
+                                        .xsmall()
+                                        .disabled(!self.can_navigate_pane(pane, -1))
+                                        .on_click(cx.listener(move |view, _, _, cx| {
+                                            view.navigate_pane(pane, -1, cx)
+                                        })),
+                                )
+                                .child(
+                                    h_flex()
+                                        .gap_2()
+                                        .child(self.month_name(offset_month))
+                                        .child(year.to_string()),
+                                )
+                                .child(
+                                    Button::new(("pane-next", pane))
+                                        .icon(next_icon.clone())
+                                        .tooltip(t!("Calendar.next_month"))
+                                        .ghost()
+                                        .xsmall()
+                                        .disabled(!self.can_navigate_pane(pane, 1))
+                                        .on_click(cx.listener(move |view, _, _, cx| {
+                                            view.navigate_pane(pane, 1, cx)
+                                        })),
                                 ),
+                        )
+                    })
+                    .child(
+                        h_flex()
+                            .gap_0p5()
+                            .when(connected, |this| this.gap_0())
+                            .justify_between()
+                            .when(rtl, |this| this.flex_row_reverse())
+                            .children(
+                                weeks
+                                    .iter()
+                                    .map(|week| self.render_week(week.clone(), window, cx)),
+                            ),
+                    )
+                    .children(days.iter().map(|week| {
+                        h_flex()
+                            .gap_0p5()
+                            .when(connected, |this| this.gap_0())
+                            .justify_between()
+                            .when(rtl, |this| this.flex_row_reverse())
+                            .children(
+                                week.iter()
+                                    .map(|d| self.render_day(d, offset_month, window, cx)),
                             )
-                            .children(days.iter().map(|week| {
-                                h_flex().gap_0p5().justify_between().children(
-                                    week.iter()
-                                        .map(|d| self.render_day(d, offset_month, window, cx)),
-                                )
-                            }))
-                    }),
-            )
+                    }))
+            }))
     }
 
     fn render_months(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
@@ -742,6 +2218,7 @@ impl Calendar {
             .map(|this| match self.size {
                 Size::Small => this.mt_2().gap_y_2().w(px(208.)),
                 Size::Large => this.mt_4().gap_y_4().w(px(292.)),
+                Size::XLarge => this.mt_5().gap_y_5().w(px(348.)),
                 _ => this.mt_3().gap_y_3().w(px(264.)),
             })
             .justify_between()
@@ -751,7 +2228,13 @@ impl Calendar {
                     .iter()
                     .enumerate()
                     .map(|(ix, month)| {
-                        let active = (ix + 1) as u8 == self.current_month;
+                        let active = if self.granularity == Granularity::Month {
+                            self.date.start().map_or(false, |date| {
+                                date.year() == self.current_year && date.month() == ix as u32 + 1
+                            })
+                        } else {
+                            (ix + 1) as u8 == self.current_month
+                        };
 
                         self.item_button(
                             ix,
@@ -767,8 +2250,17 @@ impl Calendar {
                         .text_sm()
                         .on_click(cx.listener(
                             move |view, _, window, cx| {
-                                view.current_month = (ix + 1) as u8;
-                                view.set_view_mode(ViewMode::Day, window, cx);
+                                view.navigate(view.current_year, (ix + 1) as u8, cx);
+                                if view.granularity == Granularity::Month {
+                                    if let Some(date) =
+                                        NaiveDate::from_ymd_opt(view.current_year, ix as u32 + 1, 1)
+                                    {
+                                        view.set_date(date, window, cx);
+                                        cx.emit(CalendarEvent::Selected(view.date()));
+                                    }
+                                } else {
+                                    view.set_view_mode(ViewMode::Day, window, cx);
+                                }
                                 cx.notify();
                             },
                         ))
@@ -777,6 +2269,51 @@ impl Calendar {
             )
     }
 
+    fn render_quarters(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        h_flex()
+            .gap_0p5()
+            .map(|this| match self.size {
+                Size::Small => this.mt_2().gap_y_2().w(px(208.)),
+                Size::Large => this.mt_4().gap_y_4().w(px(292.)),
+                Size::XLarge => this.mt_5().gap_y_5().w(px(348.)),
+                _ => this.mt_3().gap_y_3().w(px(264.)),
+            })
+            .justify_between()
+            .flex_wrap()
+            .children(
+                (1..=4)
+                    .map(|quarter| {
+                        let active = self.date.start().map_or(false, |date| {
+                            date.year() == self.current_year && quarter_of(&date) == quarter
+                        });
+
+                        self.item_button(
+                            quarter as usize,
+                            format!("Q{}", quarter),
+                            active,
+                            false,
+                            false,
+                            false,
+                            window,
+                            cx,
+                        )
+                        .w(relative(0.45))
+                        .text_sm()
+                        .on_click(cx.listener(
+                            move |view, _, window, cx| {
+                                if let Some((start, end)) =
+                                    quarter_range(view.current_year, quarter)
+                                {
+                                    view.set_date(Date::Range(Some(start), Some(end)), window, cx);
+                                    cx.emit(CalendarEvent::Selected(view.date()));
+                                }
+                            },
+                        ))
+                    })
+                    .collect::<Vec<_>>(),
+            )
+    }
+
     fn render_years(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         let current_page_years = &self.years[self.year_page as usize];
 
@@ -786,6 +2323,7 @@ impl Calendar {
             .map(|this| match self.size {
                 Size::Small => this.mt_2().gap_y_2().w(px(208.)),
                 Size::Large => this.mt_4().gap_y_4().w(px(292.)),
+                Size::XLarge => this.mt_5().gap_y_5().w(px(348.)),
                 _ => this.mt_3().gap_y_3().w(px(264.)),
             })
             .justify_between()
@@ -796,7 +2334,11 @@ impl Calendar {
                     .enumerate()
                     .map(|(ix, year)| {
                         let year = *year;
-                        let active = year == self.current_year;
+                        let active = if self.granularity == Granularity::Year {
+                            self.date.start().map_or(false, |date| date.year() == year)
+                        } else {
+                            year == self.current_year
+                        };
 
                         self.item_button(
                             ix,
@@ -811,8 +2353,19 @@ impl Calendar {
                         .w(relative(0.2))
                         .on_click(cx.listener(
                             move |view, _, window, cx| {
-                                view.current_year = year;
-                                view.set_view_mode(ViewMode::Day, window, cx);
+                                view.navigate(year, view.current_month, cx);
+                                if view.granularity == Granularity::Year {
+                                    if let Some((start, end)) = year_range(year) {
+                                        view.set_date(
+                                            Date::Range(Some(start), Some(end)),
+                                            window,
+                                            cx,
+                                        );
+                                        cx.emit(CalendarEvent::Selected(view.date()));
+                                    }
+                                } else {
+                                    view.set_view_mode(view.base_view_mode(), window, cx);
+                                }
                                 cx.notify();
                             },
                         ))
@@ -828,25 +2381,98 @@ impl Sizable for Calendar {
         self
     }
 }
+
+impl Focusable for Calendar {
+    fn focus_handle(&self, _: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
 impl EventEmitter<CalendarEvent> for Calendar {}
 
 impl Render for Calendar {
     fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl gpui::IntoElement {
-        v_flex()
+        let rtl = crate::direction(cx).is_rtl();
+        let quick_jump_input =
+            (self.quick_jump && self.view_mode.is_day()).then(|| self.quick_jump_input(window, cx));
+
+        h_flex()
+            .key_context(KEY_CONTEXT)
             .track_focus(&self.focus_handle)
-            .gap_0p5()
-            .child(self.render_header(window, cx))
+            .on_action(cx.listener(Self::on_action_prev_month))
+            .on_action(cx.listener(Self::on_action_next_month))
+            .on_action(cx.listener(Self::on_action_prev_year))
+            .on_action(cx.listener(Self::on_action_next_year))
+            .on_action(cx.listener(Self::on_action_extend_prev_day))
+            .on_action(cx.listener(Self::on_action_extend_next_day))
+            .on_action(cx.listener(Self::on_action_extend_prev_week))
+            .on_action(cx.listener(Self::on_action_extend_next_week))
+            .on_action(cx.listener(Self::on_action_confirm_range))
+            .on_key_down(cx.listener(Self::on_key_down))
+            .gap_3()
+            .when(rtl, |this| this.flex_row_reverse())
+            .items_start()
+            .when_some(self.presets.clone(), |this, presets| {
+                this.child(v_flex().my_1().gap_2().justify_end().children(
+                    presets.into_iter().enumerate().map(|(i, preset)| {
+                        let disabled = self.loading
+                            || preset.is_disabled()
+                            || self.is_date_disabled(&preset.date());
+
+                        Button::new(("preset", i))
+                            .small()
+                            .ghost()
+                            .label(preset.label().clone())
+                            .disabled(disabled)
+                            .on_click(cx.listener(move |view, _, window, cx| {
+                                if disabled {
+                                    return;
+                                }
+
+                                view.set_date(preset.date(), window, cx);
+                                cx.emit(CalendarEvent::Selected(view.date()));
+                            }))
+                    }),
+                ))
+            })
             .child(
                 v_flex()
-                    .when(self.view_mode.is_day(), |this| {
-                        this.child(self.render_days(window, cx))
-                    })
-                    .when(self.view_mode.is_month(), |this| {
-                        this.child(self.render_months(window, cx))
-                    })
-                    .when(self.view_mode.is_year(), |this| {
-                        this.child(self.render_years(window, cx))
-                    }),
+                    .gap_0p5()
+                    .child(self.render_header(window, cx))
+                    .children(quick_jump_input)
+                    .child(
+                        v_flex()
+                            .relative()
+                            .when(self.view_mode.is_day(), |this| {
+                                this.child(self.render_days(window, cx))
+                            })
+                            .when(self.view_mode.is_month(), |this| {
+                                this.child(self.render_months(window, cx))
+                            })
+                            .when(self.view_mode.is_quarter(), |this| {
+                                this.child(self.render_quarters(window, cx))
+                            })
+                            .when(self.view_mode.is_year(), |this| {
+                                this.child(self.render_years(window, cx))
+                            })
+                            .when(self.loading, |this| {
+                                this.child(
+                                    div()
+                                        .id("loading")
+                                        .absolute()
+                                        .inset_0()
+                                        .flex()
+                                        .items_center()
+                                        .justify_center()
+                                        .bg(cx.theme().background.opacity(0.75))
+                                        .occlude()
+                                        .child(
+                                            Indicator::new()
+                                                .with_size(self.size)
+                                                .color(cx.theme().muted_foreground),
+                                        ),
+                                )
+                            }),
+                    ),
             )
     }
 }
@@ -855,7 +2481,7 @@ impl Render for Calendar {
 mod tests {
     use chrono::NaiveDate;
 
-    use super::Date;
+    use super::{Date, Matcher};
 
     #[test]
     fn test_date_to_string() {
@@ -880,4 +2506,141 @@ mod tests {
         let date = Date::Range(None, None);
         assert_eq!(date.to_string(), "nil");
     }
+
+    #[test]
+    fn test_date_toggle() {
+        let day = |d| NaiveDate::from_ymd_opt(2024, 8, d).unwrap();
+
+        let mut date = Date::Multiple(vec![]);
+        assert!(date.toggle(day(5), Some(2)));
+        assert!(date.toggle(day(3), Some(2)));
+        assert_eq!(date, Date::Multiple(vec![day(3), day(5)]));
+        assert_eq!(date.to_string(), "2024-08-03, 2024-08-05");
+        assert_eq!(date.format("%m/%d"), Some("08/03, 08/05".into()));
+
+        // The max is reached, but the selected day can still be deselected.
+        assert!(!date.toggle(day(4), Some(2)));
+        assert!(date.toggle(day(3), Some(2)));
+        assert_eq!(date, Date::Multiple(vec![day(5)]));
+        assert_eq!(date.count(), 1);
+        assert!(date.is_complete());
+
+        assert!(date.toggle(day(5), None));
+        assert!(!date.is_some());
+        assert_eq!(date.to_string(), "nil");
+
+        let mut date = Date::Single(None);
+        assert!(!date.toggle(day(3), None));
+        assert_eq!(date, Date::Single(None));
+    }
+
+    #[test]
+    fn test_matcher_combinators() {
+        let date = |d| NaiveDate::from_ymd_opt(2024, 8, d).unwrap();
+        // 2024-08-03 is Saturday, 2024-08-04 is Sunday, 2024-08-05 is Monday.
+        let weekends = || Matcher::DayOfWeek(vec![0, 6]);
+        let holidays = || Matcher::range(Some(date(5)), Some(date(6)));
+
+        let matcher = Matcher::any(vec![weekends(), holidays()]);
+        assert!(matcher.matched(&date(3)));
+        assert!(matcher.matched(&date(5)));
+        assert!(!matcher.matched(&date(7)));
+
+        let matcher = Matcher::all(vec![weekends(), Matcher::range(Some(date(4)), None)]);
+        assert!(!matcher.matched(&date(3)));
+        assert!(matcher.matched(&date(4)));
+        assert!(!matcher.matched(&date(5)));
+
+        let matcher = Matcher::all(vec![
+            Matcher::any(vec![weekends(), holidays()]),
+            Matcher::not(Matcher::range(Some(date(4)), Some(date(5)))),
+        ]);
+        assert!(matcher.matched(&date(3)));
+        assert!(!matcher.matched(&date(4)));
+        assert!(!matcher.matched(&date(5)));
+        assert!(matcher.matched(&date(6)));
+        assert!(!matcher.matched(&date(7)));
+
+        assert!(!Matcher::any(vec![]).matched(&date(3)));
+        assert!(Matcher::all(vec![]).matched(&date(3)));
+    }
+
+    #[test]
+    fn test_matcher_reason() {
+        let date = |d| NaiveDate::from_ymd_opt(2024, 8, d).unwrap();
+        let matcher = Matcher::any(vec![
+            Matcher::DayOfWeek(vec![0, 6]),
+            Matcher::range(Some(date(5)), Some(date(6))).with_reason("Fully booked"),
+            Matcher::interval(Some(date(2)), None).with_reason("Past date"),
+        ]);
+
+        assert!(matcher.matched(&date(3)));
+        assert_eq!(matcher.reason(&date(3)), None);
+        assert_eq!(matcher.reason(&date(5)), Some("Fully booked".into()));
+        assert_eq!(matcher.reason(&date(1)), Some("Past date".into()));
+        assert_eq!(matcher.reason(&date(7)), None);
+    }
+
+    #[test]
+    fn test_matcher_bounds() {
+        let date = |d| NaiveDate::from_ymd_opt(2024, 8, d).unwrap();
+        assert_eq!(Matcher::DayOfWeek(vec![0, 6]).bounds(), (None, None));
+        assert_eq!(
+            Matcher::interval(Some(date(2)), None).bounds(),
+            (Some(date(2)), None)
+        );
+        assert_eq!(
+            Matcher::not(Matcher::range(Some(date(3)), Some(date(20)))).bounds(),
+            (Some(date(3)), Some(date(20)))
+        );
+
+        let matcher = Matcher::any(vec![
+            Matcher::DayOfWeek(vec![0, 6]),
+            Matcher::interval(Some(date(2)), Some(date(25))).with_reason("Out of range"),
+            Matcher::interval(Some(date(5)), None),
+            Matcher::interval(None, Some(date(28))),
+        ]);
+        assert_eq!(matcher.bounds(), (Some(date(5)), Some(date(25))));
+    }
+
+    #[test]
+    fn test_date_format() {
+        let start = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+
+        let date = Date::Single(Some(start));
+        assert!(date.is_some());
+        assert_eq!(date.format("%Y/%m/%d"), Some("2024/03/01".into()));
+
+        let date = Date::Range(Some(start), Some(end));
+        assert!(date.is_some());
+        assert_eq!(
+            date.format("%Y/%m/%d"),
+            Some("2024/03/01 - 2024/03/15".into())
+        );
+
+        let date = Date::Range(Some(start), None);
+        assert!(date.is_some());
+        assert_eq!(date.format("%Y/%m/%d"), Some("2024/03/01 - …".into()));
+
+        let date = Date::Range(None, Some(end));
+        assert!(date.is_some());
+        assert_eq!(date.format("%Y/%m/%d"), Some("… - 2024/03/15".into()));
+
+        let date = Date::Range(None, None);
+        assert!(!date.is_some());
+        assert_eq!(date.format("%Y/%m/%d"), None);
+        assert!(!Date::Single(None).is_some());
+
+        let date = Date::Range(Some(start), Some(end));
+        assert_eq!(
+            date.format_with_separator("%m/%d", " to "),
+            Some("03/01 to 03/15".into())
+        );
+        let date = Date::Range(Some(start), None);
+        assert_eq!(
+            date.format_with_separator("%m/%d", " – "),
+            Some("03/01 – …".into())
+        );
+    }
 }
@@ -0,0 +1,82 @@
+use gpui::{
+    div, prelude::FluentBuilder as _, App, Div, IntoElement, ParentElement as _, RenderOnce,
+    SharedString, Styled, Window,
+};
+
+use crate::ActiveTheme as _;
+
+use super::calendar::{Date, Granularity};
+
+/// A read-only label to display a [`Date`] in the same format as the `DatePicker`,
+/// e.g. in a table cell or a tag.
+///
+/// ```ignore
+/// DateLabel::new(date).format("%Y-%m-%d").placeholder("No date")
+/// ```
+#[derive(IntoElement)]
+pub struct DateLabel {
+    base: Div,
+    date: Date,
+    format: SharedString,
+    range_separator: SharedString,
+    granularity: Granularity,
+    placeholder: Option<SharedString>,
+}
+
+impl DateLabel {
+    pub fn new(date: impl Into<Date>) -> Self {
+        Self {
+            base: div(),
+            date: date.into(),
+            format: "%Y/%m/%d".into(),
+            range_separator: " - ".into(),
+            granularity: Granularity::default(),
+            placeholder: None,
+        }
+    }
+
+    /// Set the date format, default is `%Y/%m/%d`.
+    pub fn format(mut self, format: impl Into<SharedString>) -> Self {
+        self.format = format.into();
+        self
+    }
+
+    /// Set the separator between the range dates, default is `" - "`.
+    pub fn range_separator(mut self, separator: impl Into<SharedString>) -> Self {
+        self.range_separator = separator.into();
+        self
+    }
+
+    /// Set the granularity of the date, e.g. `Granularity::Quarter` displays `2024 Q3`.
+    pub fn granularity(mut self, granularity: Granularity) -> Self {
+        self.granularity = granularity;
+        self
+    }
+
+    /// Set the placeholder to display if there is no date, default is empty.
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+}
+
+impl Styled for DateLabel {
+    fn style(&mut self) -> &mut gpui::StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for DateLabel {
+    fn render(self, _: &mut Window, cx: &mut App) -> impl IntoElement {
+        let text = self
+            .date
+            .display_in(self.granularity, &self.format, &self.range_separator);
+
+        self.base.whitespace_nowrap().map(|this| match text {
+            Some(text) => this.child(text),
+            None => this
+                .text_color(cx.theme().muted_foreground)
+                .children(self.placeholder),
+        })
+    }
+}
@@ -1,30 +1,78 @@
-use chrono::NaiveDate;
+use std::time::Duration;
+
+use chrono::{Datelike, Local, NaiveDate};
 use gpui::{
-    anchored, deferred, div, prelude::FluentBuilder as _, px, App, AppContext, Context, ElementId,
-    Entity, EventEmitter, FocusHandle, Focusable, InteractiveElement as _, KeyBinding, Length,
-    MouseButton, ParentElement as _, Render, SharedString, StatefulInteractiveElement as _, Styled,
-    Subscription, Window,
+    actions, anchored, deferred, div, prelude::FluentBuilder as _, px, AnyElement, App, AppContext,
+    Axis, Context, ElementId, Entity, EventEmitter, FocusHandle, Focusable, Hsla,
+    InteractiveElement as _, IntoElement, KeyBinding, KeyDownEvent, Length, MouseButton,
+    ParentElement as _, Pixels, Render, SharedString, StatefulInteractiveElement as _, Styled,
+    Subscription, Task, Timer, Window,
 };
 use rust_i18n::t;
 
 use crate::{
     actions::Cancel,
     button::{Button, ButtonVariants as _},
+    dropdown::{Dropdown, DropdownEvent},
+    form::Validatable,
     h_flex,
-    input::clear_button,
-    v_flex, ActiveTheme, Icon, IconName, Sizable, Size, StyleSized as _, StyledExt as _,
+    input::sized_clear_button,
+    tooltip::Tooltip,
+    v_flex, ActiveTheme, Disableable as _, FocusableCycle, Icon, IconName, Sizable, Size,
+    StyleSized as _, StyledExt as _, TabStop,
+};
+
+use super::calendar::{
+    Calendar, CalendarEvent, ConfirmRange, Date, Density, ExtendNextDay, ExtendNextWeek,
+    ExtendPrevDay, ExtendPrevWeek, Granularity, Matcher, NextMonth, NextYear, PrevMonth, PrevYear,
+    RangeEndpoint, SelectionShape,
+};
+use super::utils::{
+    parse_date, parse_date_range, parse_relative_date, RelativeKeywords, RANGE_SEPARATORS,
 };
 
-use super::calendar::{Calendar, CalendarEvent, Date, Matcher};
+/// The time to wait for the second click when `confirm_clear` is enabled.
+const CLEAR_CONFIRM_TIMEOUT: Duration = Duration::from_secs(3);
+
+actions!(date_picker, [Tab, TabPrev, Clear]);
 
 pub fn init(cx: &mut App) {
     let context = Some("DatePicker");
-    cx.bind_keys([KeyBinding::new("escape", Cancel, context)])
+    cx.bind_keys([
+        KeyBinding::new("escape", Cancel, context),
+        KeyBinding::new("tab", Tab, context),
+        KeyBinding::new("shift-tab", TabPrev, context),
+        KeyBinding::new("pageup", PrevMonth, context),
+        KeyBinding::new("pagedown", NextMonth, context),
+        KeyBinding::new("shift-pageup", PrevYear, context),
+        KeyBinding::new("shift-pagedown", NextYear, context),
+        KeyBinding::new("shift-left", ExtendPrevDay, context),
+        KeyBinding::new("shift-right", ExtendNextDay, context),
+        KeyBinding::new("shift-up", ExtendPrevWeek, context),
+        KeyBinding::new("shift-down", ExtendNextWeek, context),
+        KeyBinding::new("enter", ConfirmRange, context),
+        KeyBinding::new("backspace", Clear, context),
+        KeyBinding::new("delete", Clear, context),
+    ])
 }
 
 #[derive(Clone)]
 pub enum DatePickerEvent {
     Change(Date),
+    /// The visible month (1..=12) of the calendar is changed by the user navigation,
+    /// e.g. to fetch the data of the month.
+    MonthChanged(i32, u32),
+}
+
+/// The layout to display the range date in the date picker input.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RangeLayout {
+    /// Display the range in one field, this is the default.
+    #[default]
+    Single,
+    /// Display the start and end dates in two adjacent fields, each field opens the calendar
+    /// to update that endpoint.
+    Split,
 }
 
 #[derive(Clone)]
@@ -37,6 +85,7 @@ pub enum DateRangePresetValue {
 pub struct DateRangePreset {
     label: SharedString,
     value: DateRangePresetValue,
+    disabled: bool,
 }
 
 impl DateRangePreset {
@@ -45,6 +94,7 @@ impl DateRangePreset {
         DateRangePreset {
             label: label.into(),
             value: DateRangePresetValue::Single(single),
+            disabled: false,
         }
     }
     /// Creates a new DateRangePreset with a range of dates.
@@ -52,29 +102,181 @@ impl DateRangePreset {
         DateRangePreset {
             label: label.into(),
             value: DateRangePresetValue::Range(start, end),
+            disabled: false,
+        }
+    }
+
+    /// Set the preset to be disabled, default is `false`.
+    ///
+    /// The preset is also disabled automatically if its date is matched by the disabled matcher
+    /// of the calendar, e.g. out of the `Matcher::interval` bounds.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Returns true if the preset is set to be disabled.
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Returns the label of the preset.
+    pub fn label(&self) -> &SharedString {
+        &self.label
+    }
+
+    /// Returns the date of the preset.
+    pub fn date(&self) -> Date {
+        match self.value {
+            DateRangePresetValue::Single(single) => Date::Single(Some(single)),
+            DateRangePresetValue::Range(start, end) => Date::Range(Some(start), Some(end)),
+        }
+    }
+}
+
+/// The style to render the presets in the popup of the [`DatePicker`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PresetsStyle {
+    /// A column of buttons beside the calendar, this is the default.
+    #[default]
+    Buttons,
+    /// A dropdown above the calendar, to keep the popup compact with many presets.
+    Dropdown,
+}
+
+/// The relative date keywords of the current locale.
+fn relative_keywords() -> RelativeKeywords {
+    let aliases = |text: std::borrow::Cow<str>| RelativeKeywords::aliases(&text);
+    RelativeKeywords {
+        today: aliases(t!("DatePicker.relative.today")),
+        tomorrow: aliases(t!("DatePicker.relative.tomorrow")),
+        yesterday: aliases(t!("DatePicker.relative.yesterday")),
+        next: aliases(t!("DatePicker.relative.next")),
+        last: aliases(t!("DatePicker.relative.last")),
+        in_: aliases(t!("DatePicker.relative.in")),
+        later: aliases(t!("DatePicker.relative.later")),
+        ago: aliases(t!("DatePicker.relative.ago")),
+        units: [
+            aliases(t!("DatePicker.relative.days")),
+            aliases(t!("DatePicker.relative.weeks")),
+            aliases(t!("DatePicker.relative.months")),
+            aliases(t!("DatePicker.relative.years")),
+        ],
+        weekdays: [
+            aliases(t!("DatePicker.relative.weekday.0")),
+            aliases(t!("DatePicker.relative.weekday.1")),
+            aliases(t!("DatePicker.relative.weekday.2")),
+            aliases(t!("DatePicker.relative.weekday.3")),
+            aliases(t!("DatePicker.relative.weekday.4")),
+            aliases(t!("DatePicker.relative.weekday.5")),
+            aliases(t!("DatePicker.relative.weekday.6")),
+        ],
+    }
+}
+
+/// The actions to show in the footer of the calendar popup of the [`DatePicker`].
+///
+/// The footer is hidden if no action is enabled, this is the default.
+///
+/// ```ignore
+/// DatePicker::new("date", window, cx).footer_actions(FooterActions {
+///     today: true,
+///     clear: true,
+///     ..Default::default()
+/// })
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FooterActions {
+    /// Select today, or the range of today.
+    pub today: bool,
+    /// Clear the date.
+    pub clear: bool,
+    /// Commit the range extended by the keyboard, and close the popup.
+    pub apply: bool,
+    /// Discard the range extended by the keyboard, and close the popup.
+    pub cancel: bool,
+}
+
+impl FooterActions {
+    /// Show all the actions.
+    pub fn all() -> Self {
+        Self {
+            today: true,
+            clear: true,
+            apply: true,
+            cancel: true,
         }
     }
+
+    /// Returns true if no action is enabled.
+    pub fn is_empty(&self) -> bool {
+        !(self.today || self.clear || self.apply || self.cancel)
+    }
 }
+
 pub struct DatePicker {
     id: ElementId,
     focus_handle: FocusHandle,
     date: Date,
     cleanable: bool,
+    confirm_clear: bool,
+    /// Waiting for the second click to clear the date, when `confirm_clear` is enabled,
+    /// and the task to reset it, dropped to cancel.
+    clear_confirming: Option<Task<()>>,
+    stay_open_on_select: bool,
+    quick_jump: bool,
+    allow_relative: bool,
+    popup_shadow: bool,
+    popup_radius: Option<Pixels>,
+    /// Focus the date picker at the next render, reset after focused.
+    autofocus: bool,
+    tab_index: Option<isize>,
+    disabled: bool,
+    loading: bool,
     placeholder: Option<SharedString>,
+    aria_label: Option<SharedString>,
+    /// The placeholders of the start and end date for the range picker.
+    range_placeholders: Option<(SharedString, SharedString)>,
+    range_layout: RangeLayout,
+    empty_content: Option<Box<dyn Fn(&mut Window, &mut App) -> AnyElement + 'static>>,
+    placeholder_color: Option<Hsla>,
     open: bool,
     size: Size,
     width: Length,
     date_format: SharedString,
+    parse_formats: Vec<SharedString>,
+    range_separator: SharedString,
     calendar: Entity<Calendar>,
+    /// The month to show when opening without a date.
+    default_view_month: Option<NaiveDate>,
+    density: Density,
+    fixed_rows: bool,
+    selection_shape: SelectionShape,
     number_of_months: usize,
+    max_selections: Option<usize>,
+    independent_panes: bool,
+    orientation: Axis,
+    granularity: Granularity,
     presets: Option<Vec<DateRangePreset>>,
+    presets_style: PresetsStyle,
+    /// The dropdown of the presets, created at the first render with `PresetsStyle::Dropdown`.
+    presets_dropdown: Option<Entity<Dropdown<Vec<SharedString>>>>,
+    /// The focus handles of the preset buttons, created at the render.
+    preset_focus_handles: Vec<FocusHandle>,
+    footer_actions: FooterActions,
+    on_change: Option<Box<dyn Fn(&Date, &mut Window, &mut App) + 'static>>,
+    on_open_change: Option<Box<dyn Fn(bool, &mut Window, &mut App) + 'static>>,
+    on_close: Option<Box<dyn Fn(&Date, &mut Window, &mut App) + 'static>>,
+    on_month_change: Option<Box<dyn Fn(i32, u32, &mut Window, &mut App) + 'static>>,
+    /// The model bound by [`DatePicker::bind`], with the subscription to observe it.
+    model: Option<(Entity<Date>, Subscription)>,
     _subscriptions: Vec<Subscription>,
 }
 
 impl DatePicker {
     /// Create a date picker.
     pub fn new(id: impl Into<ElementId>, window: &mut Window, cx: &mut Context<Self>) -> Self {
-        Self::new_with_range(id, false, window, cx)
+        Self::new_with_date(id, Date::Single(None), window, cx)
     }
 
     /// Create a date picker with range mode.
@@ -83,24 +285,127 @@ impl DatePicker {
         window: &mut Window,
         cx: &mut Context<Self>,
     ) -> Self {
-        Self::new_with_range(id, true, window, cx)
+        Self::new_with_date(id, Date::Range(None, None), window, cx)
     }
 
-    fn new_with_range(
+    /// Create a date picker to select multiple days, clicking a day selects or deselects it.
+    ///
+    /// The popup stays open while selecting, use [`DatePicker::max_selections`] to limit the days.
+    pub fn multiple_picker(
         id: impl Into<ElementId>,
-        is_range: bool,
         window: &mut Window,
         cx: &mut Context<Self>,
     ) -> Self {
-        let date = if is_range {
-            Date::Range(None, None)
-        } else {
-            Date::Single(None)
-        };
+        Self::new_with_date(id, Date::Multiple(vec![]), window, cx)
+    }
+
+    /// Create a week picker, clicking any day selects the whole week (Sunday to Saturday).
+    ///
+    /// The date will be a range spanning the week.
+    pub fn week_picker(
+        id: impl Into<ElementId>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        let mut this = Self::new_with_date(id, Date::Range(None, None), window, cx);
+        this.set_granularity(Granularity::Week, window, cx);
+        this
+    }
+
+    /// Create a month picker, to select a whole month instead of a day.
+    ///
+    /// The date will be the first day of the selected month, and displayed as `%Y/%m`.
+    pub fn month_picker(
+        id: impl Into<ElementId>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        let mut this = Self::new_with_date(id, Date::Single(None), window, cx);
+        this.date_format = "%Y/%m".into();
+        this.set_granularity(Granularity::Month, window, cx);
+        this
+    }
+
+    /// Create a quarter picker, to select a quarter (Q1 - Q4) of the year.
+    ///
+    /// The date will be a range spanning the quarter, and displayed as `2024 Q1`.
+    pub fn quarter_picker(
+        id: impl Into<ElementId>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        let mut this = Self::new_with_date(id, Date::Range(None, None), window, cx);
+        this.date_format = "%Y".into();
+        this.set_granularity(Granularity::Quarter, window, cx);
+        this
+    }
 
+    /// Create a year picker, to select a whole year.
+    ///
+    /// The date will be a range spanning the year, and displayed as `%Y`.
+    pub fn year_picker(
+        id: impl Into<ElementId>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        let mut this = Self::new_with_date(id, Date::Range(None, None), window, cx);
+        this.date_format = "%Y".into();
+        this.set_granularity(Granularity::Year, window, cx);
+        this
+    }
+
+    fn set_granularity(
+        &mut self,
+        granularity: Granularity,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.granularity = granularity;
+        self.calendar.update(cx, |view, cx| {
+            view.set_granularity(granularity, window, cx);
+        });
+    }
+
+    /// Returns the formatted date to display in the input.
+    fn display_title(&self) -> Option<SharedString> {
+        let by_day = matches!(
+            self.granularity,
+            Granularity::Day | Granularity::Week | Granularity::Month
+        );
+        match (&self.date, &self.range_placeholders) {
+            (Date::Range(Some(start), None), Some((_, end))) if by_day => Some(
+                format!(
+                    "{}{}{}",
+                    start.format(&self.date_format),
+                    self.range_separator,
+                    end
+                )
+                .into(),
+            ),
+            (Date::Range(None, Some(end)), Some((start, _))) if by_day => Some(
+                format!(
+                    "{}{}{}",
+                    start,
+                    self.range_separator,
+                    end.format(&self.date_format)
+                )
+                .into(),
+            ),
+            _ => self
+                .date
+                .display_in(self.granularity, &self.date_format, &self.range_separator),
+        }
+    }
+
+    fn new_with_date(
+        id: impl Into<ElementId>,
+        date: Date,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
         let calendar = cx.new(|cx| {
             let mut this = Calendar::new(window, cx);
-            this.set_date(date, window, cx);
+            this.set_date(date.clone(), window, cx);
             this
         });
 
@@ -109,8 +414,17 @@ impl DatePicker {
             window,
             |this, _, ev: &CalendarEvent, window, cx| match ev {
                 CalendarEvent::Selected(date) => {
-                    this.update_date(*date, true, window, cx);
-                    this.focus_handle.focus(window);
+                    this.update_date(date.clone(), true, window, cx);
+                    // Keep the focus in the calendar to select more days.
+                    if !date.is_multiple() {
+                        this.focus_handle.focus(window);
+                    }
+                }
+                CalendarEvent::MonthChanged { year, month } => {
+                    if let Some(on_month_change) = this.on_month_change.as_ref() {
+                        on_month_change(*year, *month, window, cx);
+                    }
+                    cx.emit(DatePickerEvent::MonthChanged(*year, *month));
                 }
             },
         )];
@@ -124,10 +438,45 @@ impl DatePicker {
             size: Size::default(),
             width: Length::Auto,
             date_format: "%Y/%m/%d".into(),
+            parse_formats: vec![],
+            range_separator: " - ".into(),
             cleanable: false,
+            confirm_clear: false,
+            clear_confirming: None,
+            stay_open_on_select: false,
+            quick_jump: false,
+            allow_relative: false,
+            popup_shadow: true,
+            popup_radius: None,
+            autofocus: false,
+            tab_index: None,
+            disabled: false,
+            loading: false,
+            default_view_month: None,
+            density: Density::default(),
+            fixed_rows: true,
+            selection_shape: SelectionShape::default(),
             number_of_months: 1,
+            max_selections: None,
+            independent_panes: false,
+            orientation: Axis::Horizontal,
+            granularity: Granularity::Day,
             placeholder: None,
+            aria_label: None,
+            range_placeholders: None,
+            range_layout: RangeLayout::default(),
+            empty_content: None,
+            placeholder_color: None,
             presets: None,
+            presets_style: PresetsStyle::default(),
+            presets_dropdown: None,
+            preset_focus_handles: Vec::new(),
+            footer_actions: FooterActions::default(),
+            on_change: None,
+            on_close: None,
+            on_open_change: None,
+            on_month_change: None,
+            model: None,
             _subscriptions,
         }
     }
@@ -138,39 +487,314 @@ impl DatePicker {
         self
     }
 
-    /// Set the placeholder of the date picker, default: "".
+    /// Set the formats to parse the typed date text, tried in order, e.g.: `["%m/%d/%y", "%Y/%m/%d"]`.
+    ///
+    /// This is independent from the `date_format`, the parsed date is always displayed with
+    /// the `date_format`. If not set, only the `date_format` is accepted.
+    pub fn parse_formats(mut self, formats: Vec<SharedString>) -> Self {
+        self.parse_formats = formats;
+        self
+    }
+
+    /// Set the separator between the start and end dates of a range, default: " - ".
+    pub fn range_separator(mut self, separator: impl Into<SharedString>) -> Self {
+        self.range_separator = separator.into();
+        self
+    }
+
+    /// Set the placeholder of the date picker.
+    ///
+    /// If not set, the translated `DatePicker.placeholder` of the current locale is used,
+    /// it will follow the locale changed by [`crate::change_locale`].
     pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
         self.placeholder = Some(placeholder.into());
         self
     }
 
+    /// Set the placeholders of the start and end date for the range picker.
+    ///
+    /// When the range is partial selected, the placeholder is displayed for the missing date,
+    /// e.g. `2024/01/01 - End date`.
+    pub fn range_placeholders(
+        mut self,
+        start: impl Into<SharedString>,
+        end: impl Into<SharedString>,
+    ) -> Self {
+        self.range_placeholders = Some((start.into(), end.into()));
+        self
+    }
+
+    /// Set a custom element to display when no date is selected, instead of the placeholder.
+    pub fn empty_content<E, F>(mut self, builder: F) -> Self
+    where
+        E: IntoElement,
+        F: Fn(&mut Window, &mut App) -> E + 'static,
+    {
+        self.empty_content = Some(Box::new(move |window, cx| {
+            builder(window, cx).into_any_element()
+        }));
+        self
+    }
+
+    /// Set the layout to display the range date, default is [`RangeLayout::Single`].
+    ///
+    /// The [`RangeLayout::Split`] is only for the range date picker with `Granularity::Day`.
+    pub fn range_layout(mut self, layout: RangeLayout) -> Self {
+        self.range_layout = layout;
+        self
+    }
+
+    /// Set the label to describe the date picker for the assistive technologies,
+    /// default is the translated `DatePicker.aria_label`.
+    pub fn aria_label(mut self, label: impl Into<SharedString>) -> Self {
+        self.aria_label = Some(label.into());
+        self
+    }
+
+    /// Returns the label to describe the date picker and the selected date,
+    /// e.g. `Date picker, selected 2024/03/15`, shown as the tooltip of the input.
+    pub fn accessible_label(&self) -> SharedString {
+        let label = self
+            .aria_label
+            .clone()
+            .unwrap_or_else(|| t!("DatePicker.aria_label").into());
+        match self.display_title() {
+            Some(date) => t!("DatePicker.aria_selected", label = label, date = date).into(),
+            None => label,
+        }
+    }
+
+    /// Set the text color of the placeholder, default is `muted_foreground` of the theme.
+    pub fn placeholder_color(mut self, color: impl Into<Hsla>) -> Self {
+        self.placeholder_color = Some(color.into());
+        self
+    }
+
     /// Set true to show the clear button when the input field is not empty.
     pub fn cleanable(mut self) -> Self {
         self.cleanable = true;
         self
     }
 
+    /// Set true to require a second click on the clear button to clear the date, default is `false`.
+    ///
+    /// This is used to avoid accidental clearing of the date.
+    pub fn confirm_clear(mut self, confirm_clear: bool) -> Self {
+        self.confirm_clear = confirm_clear;
+        self
+    }
+
+    /// Set the actions to show in the footer of the calendar popup, default shows no footer.
+    pub fn footer_actions(mut self, actions: FooterActions) -> Self {
+        self.footer_actions = actions;
+        self
+    }
+
+    /// Set true to keep the calendar open after selecting a single date, default is `false`.
+    ///
+    /// The [`DatePickerEvent::Change`] event is still emitted on each selection,
+    /// the calendar is closed by pressing Escape or clicking outside.
+    pub fn stay_open_on_select(mut self, stay_open_on_select: bool) -> Self {
+        self.stay_open_on_select = stay_open_on_select;
+        self
+    }
+
+    /// Set the max number of the days to select in the [`DatePicker::multiple_picker`],
+    /// default is no limit.
+    ///
+    /// The other days are disabled once reached, and the count is shown in the popup.
+    pub fn max_selections(mut self, max_selections: usize) -> Self {
+        self.max_selections = Some(max_selections);
+        self
+    }
+
+    /// Set true to show an input in the calendar popup to type a date to jump, default is `false`.
+    ///
+    /// See [`Calendar::quick_jump`] for the accepted text.
+    pub fn quick_jump(mut self, quick_jump: bool) -> Self {
+        self.quick_jump = quick_jump;
+        self
+    }
+
+    /// Set true to accept the relative dates in the typed text, default is `false`.
+    ///
+    /// The localized phrases like `tomorrow`, `next monday`, `in 2 weeks`, `3 days ago`
+    /// and the offsets like `+3d` are parsed from today before the `parse_formats`.
+    /// This is only for the single date picker.
+    pub fn allow_relative(mut self, allow_relative: bool) -> Self {
+        self.allow_relative = allow_relative;
+        self
+    }
+
+    /// Set false to remove the shadow of the calendar popup, default is `true`.
+    pub fn popup_shadow(mut self, popup_shadow: bool) -> Self {
+        self.popup_shadow = popup_shadow;
+        self
+    }
+
+    /// Set the corner radius of the calendar popup, default is twice the theme radius, at most 8px.
+    pub fn popup_radius(mut self, radius: impl Into<Pixels>) -> Self {
+        self.popup_radius = Some(radius.into());
+        self
+    }
+
+    /// Set true to focus the date picker on the first render, default is `false`.
+    ///
+    /// If multiple components in the window set `autofocus`, the first in render order wins.
+    pub fn autofocus(mut self, autofocus: bool) -> Self {
+        self.autofocus = autofocus;
+        self
+    }
+
+    /// Set the tab index to define the focus order independent of the layout, see [`TabStop`].
+    pub fn tab_index(mut self, tab_index: isize) -> Self {
+        self.tab_index = Some(tab_index);
+        self
+    }
+
+    /// Set the disabled state of the whole date picker, default is `false`.
+    ///
+    /// The disabled date picker can't be focused or opened, but still displays the date.
+    ///
+    /// To disable some dates of the calendar, use [`DatePicker::set_disabled`] instead.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set the loading state of the calendar popup, default is `false`.
+    ///
+    /// When loading, a spinner is displayed over the calendar and the dates and presets can't be
+    /// selected. This is useful when the disabled dates are fetching asynchronously.
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
+    /// Set the loading state of the calendar popup, clear it to re-enable the selection.
+    pub fn set_loading(&mut self, loading: bool, _: &mut Window, cx: &mut Context<Self>) {
+        self.loading = loading;
+        cx.notify();
+    }
+
     /// Set width of the date picker input field, default is `Length::Auto`.
     pub fn width(mut self, width: impl Into<Length>) -> Self {
         self.width = width.into();
         self
     }
 
+    /// Set the month to show when the calendar is opened without a date, default is the current month.
+    ///
+    /// This only sets the starting view, not the selection, the month of the date still wins.
+    pub fn default_view_month(mut self, month: NaiveDate) -> Self {
+        self.default_view_month = Some(month);
+        self
+    }
+
+    /// Set the density of the day cells in the calendar, default is `Density::Normal`.
+    ///
+    /// The `size` of the date picker decides the text size, see [`Density`].
+    pub fn density(mut self, density: Density) -> Self {
+        self.density = density;
+        self
+    }
+
+    /// Set true to always render 6 weeks in the calendar, default is `true`.
+    ///
+    /// See [`Calendar::fixed_rows`].
+    pub fn fixed_rows(mut self, fixed_rows: bool) -> Self {
+        self.fixed_rows = fixed_rows;
+        self
+    }
+
+    /// Set the shape of the selected and in-range days in the calendar,
+    /// default is `SelectionShape::Rounded`.
+    pub fn selection_shape(mut self, shape: SelectionShape) -> Self {
+        self.selection_shape = shape;
+        self
+    }
+
     /// Set the number of months calendar view to display, default is 1.
     pub fn number_of_months(mut self, number_of_months: usize) -> Self {
         self.number_of_months = number_of_months;
         self
     }
 
+    /// Set true to navigate each months view of the calendar separately, default is `false`.
+    ///
+    /// The selection is still a single range across the months views,
+    /// see [`Calendar::independent_panes`].
+    pub fn independent_panes(mut self, independent_panes: bool) -> Self {
+        self.independent_panes = independent_panes;
+        self
+    }
+
+    /// Set the direction to stack the months view of the calendar, default is `Axis::Horizontal`.
+    ///
+    /// Use `Axis::Vertical` with a large `number_of_months` to keep the popup narrow,
+    /// the months view will be scrollable.
+    pub fn orientation(mut self, orientation: Axis) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
     /// Set preset ranges for the date picker.
+    ///
+    /// For `quarter_picker` and `year_picker`, use range presets that spanning the quarter or year,
+    /// e.g.: "This quarter", "Last year".
     pub fn presets(mut self, presets: Vec<DateRangePreset>) -> Self {
         self.presets = Some(presets);
         self
     }
 
+    /// Set the style to render the presets, default is `PresetsStyle::Buttons`.
+    pub fn presets_style(mut self, style: PresetsStyle) -> Self {
+        self.presets_style = style;
+        self
+    }
+
+    /// Set a callback to be called when the date is changed by the user.
+    ///
+    /// This is a shortcut of subscribing the [`DatePickerEvent::Change`] event.
+    pub fn on_change(mut self, handler: impl Fn(&Date, &mut Window, &mut App) + 'static) -> Self {
+        self.on_change = Some(Box::new(handler));
+        self
+    }
+
+    /// Set a callback to be called when the calendar popup is opened or closed.
+    ///
+    /// The argument is the new open state, it is only called when the state is actually changed.
+    pub fn on_open_change(
+        mut self,
+        handler: impl Fn(bool, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_open_change = Some(Box::new(handler));
+        self
+    }
+
+    /// Set a callback to be called when the calendar popup is closed, with the current date.
+    ///
+    /// It is called regardless of the cause, e.g. the outside click, Escape key or selection,
+    /// after the [`DatePicker::on_change`] if the popup is closed by the selection.
+    pub fn on_close(mut self, handler: impl Fn(&Date, &mut Window, &mut App) + 'static) -> Self {
+        self.on_close = Some(Box::new(handler));
+        self
+    }
+
+    /// Set a callback to be called when the visible month (1..=12) is changed by the user navigation.
+    ///
+    /// This is a shortcut of subscribing the [`DatePickerEvent::MonthChanged`] event.
+    pub fn on_month_change(
+        mut self,
+        handler: impl Fn(i32, u32, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_month_change = Some(Box::new(handler));
+        self
+    }
+
     /// Get the date of the date picker.
     pub fn date(&self) -> Date {
-        self.date
+        self.date.clone()
     }
 
     /// Set the date of the date picker.
@@ -178,14 +802,159 @@ impl DatePicker {
         self.update_date(date.into(), false, window, cx);
     }
 
-    fn update_date(&mut self, date: Date, emit: bool, window: &mut Window, cx: &mut Context<Self>) {
-        self.date = date;
+    /// Bind a model of the date, to keep it in sync with the date picker in both ways.
+    ///
+    /// The date picker is updated when the model is changed, without emitting the change event,
+    /// and the model is updated when the date is changed by the user.
+    ///
+    /// ```ignore
+    /// let model = cx.new(|_| Date::Single(None));
+    /// let picker = cx.new(|cx| DatePicker::new("date", window, cx));
+    /// picker.update(cx, |picker, cx| picker.bind(&model, window, cx));
+    /// ```
+    pub fn bind(&mut self, model: &Entity<Date>, window: &mut Window, cx: &mut Context<Self>) {
+        let subscription = cx.observe_in(model, window, |this, model, window, cx| {
+            let date = model.read(cx).clone();
+            this.sync_date(date, window, cx);
+        });
+        self.model = Some((model.clone(), subscription));
+
+        let date = model.read(cx).clone();
+        self.sync_date(date, window, cx);
+    }
+
+    /// Update the date from the bound model, the popup is kept as it is.
+    fn sync_date(&mut self, date: Date, window: &mut Window, cx: &mut Context<Self>) {
+        if self.date == date {
+            return;
+        }
+
+        self.date = date.clone();
+        self.clear_confirming = None;
         self.calendar.update(cx, |view, cx| {
             view.set_date(date, window, cx);
         });
-        self.open = false;
+        cx.notify();
+    }
+
+    /// Parse the typed date text with the `parse_formats`, or the relative date if `allow_relative`.
+    pub fn parse_date(&self, text: &str) -> Option<NaiveDate> {
+        if self.allow_relative {
+            let today = Local::now().naive_local().date();
+            if let Some(date) = parse_relative_date(text, today, &relative_keywords()) {
+                return Some(date);
+            }
+        }
+
+        if self.parse_formats.is_empty() {
+            parse_date(text, &[&self.date_format])
+        } else {
+            parse_date(text, self.parse_formats.as_slice())
+        }
+    }
+
+    /// Parse the typed or pasted date range text with the `parse_formats`.
+    ///
+    /// The text is split by the `range_separator`, or the common separators: ` - `, ` ~ `, `~`,
+    /// ` to `, ` – `. Returns the start and end that can be parsed, swapped if reversed.
+    pub fn parse_date_range(&self, text: &str) -> (Option<NaiveDate>, Option<NaiveDate>) {
+        let separators = [&[&*self.range_separator][..], &RANGE_SEPARATORS[..]].concat();
+        if self.parse_formats.is_empty() {
+            parse_date_range(text, &separators, &[&self.date_format])
+        } else {
+            parse_date_range(text, &separators, self.parse_formats.as_slice())
+        }
+    }
+
+    /// Set the date by the typed or pasted text, and emit the [`DatePickerEvent::Change`] event.
+    ///
+    /// Returns false if the text can't be parsed or the date is disabled, the date keeps unchanged.
+    ///
+    /// For the range picker, the text is parsed by [`DatePicker::parse_date_range`],
+    /// the valid endpoints are applied and the others are left unchanged.
+    ///
+    /// For the multiple picker, the text is split by `,`, and all the days must be parsed.
+    /// The days over the [`DatePicker::max_selections`] are ignored.
+    pub fn set_date_text(
+        &mut self,
+        text: &str,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> bool {
+        let date = match self.date {
+            Date::Single(_) => {
+                let Some(date) = self.parse_date(text) else {
+                    return false;
+                };
+                Date::Single(Some(date))
+            }
+            Date::Range(old_start, old_end) => {
+                let (start, end) = self.parse_date_range(text);
+                if start.is_none() && end.is_none() {
+                    return false;
+                }
+
+                match (start.or(old_start), end.or(old_end)) {
+                    (Some(start), Some(end)) if start > end => Date::Range(Some(end), Some(start)),
+                    (start, end) => Date::Range(start, end),
+                }
+            }
+            Date::Multiple(_) => {
+                let mut date = Date::Multiple(vec![]);
+                for text in text
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|text| !text.is_empty())
+                {
+                    let Some(day) = self.parse_date(text) else {
+                        return false;
+                    };
+                    date.toggle(day, self.max_selections);
+                }
+                if !date.is_some() {
+                    return false;
+                }
+                date
+            }
+        };
+        if self.calendar.read(cx).is_date_disabled(&date) {
+            return false;
+        }
+
+        self.update_date(date, true, window, cx);
+        true
+    }
+
+    fn update_date(&mut self, date: Date, emit: bool, window: &mut Window, cx: &mut Context<Self>) {
+        self.date = date.clone();
+        self.clear_confirming = None;
+        self.calendar.update(cx, |view, cx| {
+            // Already toggled by the calendar, keep the month view of the clicked day.
+            if !(date.is_multiple() && view.date() == date) {
+                view.set_date(date.clone(), window, cx);
+            }
+        });
         if emit {
-            cx.emit(DatePickerEvent::Change(date));
+            if let Some((model, _)) = self.model.as_ref() {
+                model.update(cx, |model, cx| {
+                    if *model != date {
+                        *model = date.clone();
+                        cx.notify();
+                    }
+                });
+            }
+            cx.emit(DatePickerEvent::Change(date.clone()));
+            if let Some(on_change) = self.on_change.as_ref() {
+                on_change(&date, window, cx);
+            }
+        }
+        let stay_open = match date {
+            Date::Single(_) => self.stay_open_on_select,
+            Date::Range(_, _) => false,
+            Date::Multiple(_) => true,
+        };
+        if !stay_open {
+            self.set_open(false, window, cx);
         }
         cx.notify();
     }
@@ -208,17 +977,158 @@ impl DatePicker {
         cx.notify();
     }
 
+    /// Open the calendar and focus it for the keyboard navigation, e.g. from a command palette.
+    ///
+    /// This does nothing if the date picker is disabled.
+    pub fn activate(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.disabled {
+            return;
+        }
+
+        self.set_open(true, window, cx);
+        self.calendar.focus_handle(cx).focus(window);
+    }
+
     fn escape(&mut self, _: &Cancel, window: &mut Window, cx: &mut Context<Self>) {
         if !self.open {
+            window.blur();
             cx.propagate();
+            return;
         }
 
         self.focus_back_if_need(window, cx);
-        self.open = false;
+        // The focus may be trapped in the calendar, return it to the input.
+        if self.focus_handle.contains_focused(window, cx) {
+            self.focus_handle.focus(window);
+        }
+        self.set_open(false, window, cx);
 
         cx.notify();
     }
 
+    /// Forward the typeahead to the years or months view of the calendar.
+    fn on_key_down(&mut self, event: &KeyDownEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if !self.open {
+            return;
+        }
+
+        let modifiers = event.keystroke.modifiers;
+        if modifiers.control || modifiers.alt || modifiers.platform {
+            return;
+        }
+
+        let handled = self.calendar.update(cx, |calendar, cx| {
+            calendar.typeahead(&event.keystroke.key, window, cx)
+        });
+        if handled {
+            cx.stop_propagation();
+        }
+    }
+
+    fn tab(&mut self, _: &Tab, window: &mut Window, cx: &mut Context<Self>) {
+        self.trap_focus(true, window, cx);
+    }
+
+    fn tab_prev(&mut self, _: &TabPrev, window: &mut Window, cx: &mut Context<Self>) {
+        self.trap_focus(false, window, cx);
+    }
+
+    /// Cycle the focus within the input and the calendar popup while it is open,
+    /// to prevent the focus from escaping to the elements behind the popup.
+    fn trap_focus(&mut self, is_next: bool, window: &mut Window, cx: &mut Context<Self>) {
+        if !self.open {
+            cx.propagate();
+            return;
+        }
+
+        self.cycle_focus(is_next, window, cx);
+    }
+
+    fn prev_month(&mut self, action: &PrevMonth, window: &mut Window, cx: &mut Context<Self>) {
+        if !self.open {
+            cx.propagate();
+            return;
+        }
+
+        self.calendar.update(cx, |calendar, cx| {
+            calendar.on_action_prev_month(action, window, cx)
+        });
+    }
+
+    fn next_month(&mut self, action: &NextMonth, window: &mut Window, cx: &mut Context<Self>) {
+        if !self.open {
+            cx.propagate();
+            return;
+        }
+
+        self.calendar.update(cx, |calendar, cx| {
+            calendar.on_action_next_month(action, window, cx)
+        });
+    }
+
+    fn prev_year(&mut self, action: &PrevYear, window: &mut Window, cx: &mut Context<Self>) {
+        if !self.open {
+            cx.propagate();
+            return;
+        }
+
+        self.calendar.update(cx, |calendar, cx| {
+            calendar.on_action_prev_year(action, window, cx)
+        });
+    }
+
+    fn next_year(&mut self, action: &NextYear, window: &mut Window, cx: &mut Context<Self>) {
+        if !self.open {
+            cx.propagate();
+            return;
+        }
+
+        self.calendar.update(cx, |calendar, cx| {
+            calendar.on_action_next_year(action, window, cx)
+        });
+    }
+
+    /// Forward the range extending actions to the calendar when it is open.
+    fn forward_to_calendar<A>(
+        &mut self,
+        action: &A,
+        handler: fn(&mut Calendar, &A, &mut Window, &mut Context<Calendar>),
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if !self.open {
+            cx.propagate();
+            return;
+        }
+
+        self.calendar
+            .update(cx, |calendar, cx| handler(calendar, action, window, cx));
+    }
+
+    fn set_open(&mut self, open: bool, window: &mut Window, cx: &mut Context<Self>) {
+        if self.open == open {
+            return;
+        }
+
+        self.open = open;
+        if !open {
+            self.calendar.update(cx, |calendar, cx| {
+                calendar.set_range_endpoint(None, window, cx);
+            });
+        } else if let (false, Some(month)) = (self.date.is_some(), self.default_view_month) {
+            self.calendar.update(cx, |calendar, cx| {
+                calendar.set_visible_month(month.year(), month.month(), window, cx);
+            });
+        }
+        if let Some(on_open_change) = self.on_open_change.as_ref() {
+            on_open_change(open, window, cx);
+        }
+        if let (false, Some(on_close)) = (open, self.on_close.as_ref()) {
+            on_close(&self.date, window, cx);
+        }
+        cx.notify();
+    }
+
     // To focus the Picker Input, if current focus in is on the container.
     //
     // This is because mouse down out the Calendar, GPUI will move focus to the container.
@@ -237,7 +1147,30 @@ impl DatePicker {
         }
     }
 
-    fn clean(&mut self, _: &gpui::ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+    /// Clear the date by the shortcut like the clear button, only if `cleanable` is enabled.
+    fn on_action_clear(&mut self, _: &Clear, window: &mut Window, cx: &mut Context<Self>) {
+        if !self.cleanable || self.disabled || !self.date.is_some() {
+            cx.propagate();
+            return;
+        }
+
+        self.clean(window, cx);
+    }
+
+    fn clean(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.confirm_clear && self.clear_confirming.is_none() {
+            // Reset the confirming state if not clicked again in time.
+            self.clear_confirming = Some(cx.spawn(async move |this, cx| {
+                Timer::after(CLEAR_CONFIRM_TIMEOUT).await;
+                _ = this.update(cx, |this, cx| {
+                    this.clear_confirming = None;
+                    cx.notify();
+                });
+            }));
+            cx.notify();
+            return;
+        }
+
         match self.date {
             Date::Single(_) => {
                 self.update_date(Date::Single(None), true, window, cx);
@@ -245,12 +1178,267 @@ impl DatePicker {
             Date::Range(_, _) => {
                 self.update_date(Date::Range(None, None), true, window, cx);
             }
+            Date::Multiple(_) => {
+                self.update_date(Date::Multiple(vec![]), true, window, cx);
+            }
         }
     }
 
-    fn toggle_calendar(&mut self, _: &gpui::ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
-        self.open = !self.open;
-        cx.notify();
+    fn select_today(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let today = Local::now().naive_local().date();
+        let date = match self.date {
+            Date::Single(_) => Date::Single(Some(today)),
+            Date::Range(_, _) => Date::Range(Some(today), Some(today)),
+            Date::Multiple(_) => Date::Multiple(vec![today]),
+        };
+        self.update_date(date, true, window, cx);
+    }
+
+    fn apply(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.calendar.update(cx, |calendar, cx| {
+            calendar.confirm_range_preview(window, cx);
+        });
+        self.escape(&Cancel, window, cx);
+    }
+
+    fn cancel(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let date = self.date.clone();
+        self.calendar.update(cx, |calendar, cx| {
+            calendar.set_date(date, window, cx);
+        });
+        self.escape(&Cancel, window, cx);
+    }
+
+    fn render_footer(&self, cx: &mut Context<Self>) -> Option<impl IntoElement> {
+        let actions = self.footer_actions;
+        if actions.is_empty() {
+            return None;
+        }
+
+        let today = Local::now().naive_local().date();
+        let today_disabled = self
+            .calendar
+            .read(cx)
+            .is_date_disabled(&Date::Single(Some(today)));
+
+        Some(
+            h_flex()
+                .pt_2()
+                .gap_2()
+                .border_t_1()
+                .border_color(cx.theme().border)
+                .when(actions.today, |this| {
+                    this.child(
+                        Button::new("today")
+                            .small()
+                            .ghost()
+                            .label(t!("DatePicker.today"))
+                            .disabled(today_disabled)
+                            .on_click(
+                                cx.listener(|this, _, window, cx| this.select_today(window, cx)),
+                            ),
+                    )
+                })
+                .when(actions.clear, |this| {
+                    this.child(
+                        Button::new("clear")
+                            .small()
+                            .ghost()
+                            .label(t!("Common.clear"))
+                            .disabled(!self.date.is_some())
+                            .on_click(cx.listener(|this, _, window, cx| this.clean(window, cx))),
+                    )
+                })
+                .child(div().flex_1())
+                .when(actions.cancel, |this| {
+                    this.child(
+                        Button::new("cancel")
+                            .small()
+                            .ghost()
+                            .label(t!("DatePicker.cancel"))
+                            .on_click(cx.listener(|this, _, window, cx| this.cancel(window, cx))),
+                    )
+                })
+                .when(actions.apply, |this| {
+                    this.child(
+                        Button::new("apply")
+                            .small()
+                            .primary()
+                            .label(t!("DatePicker.apply"))
+                            .on_click(cx.listener(|this, _, window, cx| this.apply(window, cx))),
+                    )
+                }),
+        )
+    }
+
+    /// Clear the start date of the range and keep the end, and emit the [`DatePickerEvent::Change`].
+    ///
+    /// This does nothing if the date picker is not in range mode.
+    pub fn clear_range_start(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if let Date::Range(Some(_), end) = self.date {
+            self.update_date(Date::Range(None, end), true, window, cx);
+        }
+    }
+
+    /// Clear the end date of the range and keep the start, and emit the [`DatePickerEvent::Change`].
+    ///
+    /// This does nothing if the date picker is not in range mode.
+    pub fn clear_range_end(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if let Date::Range(start, Some(_)) = self.date {
+            self.update_date(Date::Range(start, None), true, window, cx);
+        }
+    }
+
+    fn toggle_calendar(
+        &mut self,
+        _: &gpui::ClickEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.disabled {
+            return;
+        }
+
+        self.set_open(!self.open, window, cx);
+    }
+
+    /// Open the calendar to update the endpoint of the range, for [`RangeLayout::Split`].
+    fn open_range_endpoint(
+        &mut self,
+        endpoint: RangeEndpoint,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.disabled {
+            return;
+        }
+
+        let date = match endpoint {
+            RangeEndpoint::Start => self.date.start(),
+            RangeEndpoint::End => self.date.end(),
+        };
+        self.calendar.update(cx, |calendar, cx| {
+            calendar.set_range_endpoint(Some(endpoint), window, cx);
+            if let Some(date) = date {
+                calendar.set_visible_month(date.year(), date.month(), window, cx);
+            }
+        });
+        self.set_open(true, window, cx);
+    }
+
+    fn render_range_field(
+        &self,
+        endpoint: RangeEndpoint,
+        placeholder_color: Hsla,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let (id, date, placeholder) = match endpoint {
+            RangeEndpoint::Start => (
+                "range-start",
+                self.date.start(),
+                self.range_placeholders.as_ref().map(|(start, _)| start),
+            ),
+            RangeEndpoint::End => (
+                "range-end",
+                self.date.end(),
+                self.range_placeholders.as_ref().map(|(_, end)| end),
+            ),
+        };
+        let active = self.open && self.calendar.read(cx).range_endpoint() == Some(endpoint);
+        let show_clean = self.cleanable && !self.disabled && date.is_some();
+        let label: SharedString = match date {
+            Some(date) => date.format(&self.date_format).to_string().into(),
+            None => placeholder.cloned().unwrap_or_else(|| "…".into()),
+        };
+
+        h_flex()
+            .id(id)
+            .flex_1()
+            .gap_1()
+            .overflow_hidden()
+            .px_1()
+            .rounded(cx.theme().radius)
+            .when(active, |this| this.bg(cx.theme().accent))
+            .when(date.is_none(), |this| this.text_color(placeholder_color))
+            .child(div().flex_1().overflow_hidden().child(label))
+            .when(show_clean, |this| {
+                this.child(sized_clear_button(Size::XSmall, cx).on_click(cx.listener(
+                    move |this, _, window, cx| match endpoint {
+                        RangeEndpoint::Start => this.clear_range_start(window, cx),
+                        RangeEndpoint::End => this.clear_range_end(window, cx),
+                    },
+                )))
+            })
+            .when(!self.disabled, |this| {
+                this.on_click(cx.listener(move |this, _, window, cx| {
+                    cx.stop_propagation();
+                    this.open_range_endpoint(endpoint, window, cx);
+                }))
+            })
+    }
+
+    fn presets_dropdown(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Option<Entity<Dropdown<Vec<SharedString>>>> {
+        if self.presets_style != PresetsStyle::Dropdown {
+            return None;
+        }
+        let presets = self.presets.as_ref()?;
+        if let Some(dropdown) = self.presets_dropdown.as_ref() {
+            return Some(dropdown.clone());
+        }
+
+        let labels = presets.iter().map(|preset| preset.label.clone()).collect();
+        let dropdown = cx.new(|cx| {
+            Dropdown::new("presets", labels, None, window, cx)
+                .small()
+                .placeholder(t!("DatePicker.presets"))
+        });
+        self._subscriptions.push(cx.subscribe_in(
+            &dropdown,
+            window,
+            |this, dropdown, _: &DropdownEvent<Vec<SharedString>>, window, cx| {
+                let preset = dropdown
+                    .read(cx)
+                    .selected_index(cx)
+                    .and_then(|ix| this.presets.as_ref()?.get(ix).cloned());
+                if let Some(preset) = preset {
+                    this.select_preset(&preset, window, cx);
+                }
+            },
+        ));
+        self.presets_dropdown = Some(dropdown.clone());
+        Some(dropdown)
+    }
+
+    fn render_preset_buttons(&self, cx: &mut Context<Self>) -> Option<impl IntoElement> {
+        let presets = self.presets.clone()?;
+
+        Some(
+            v_flex()
+                .my_1()
+                .gap_2()
+                .justify_end()
+                .children(presets.into_iter().enumerate().map(|(i, preset)| {
+                    Button::new(("preset", i))
+                        .when_some(self.preset_focus_handles.get(i), |this, focus_handle| {
+                            this.track_focus(focus_handle)
+                        })
+                        .small()
+                        .ghost()
+                        .label(preset.label.clone())
+                        .disabled(self.is_preset_disabled(&preset, cx))
+                        .on_click(cx.listener(move |this, _, window, cx| {
+                            this.select_preset(&preset, window, cx);
+                        }))
+                })),
+        )
+    }
+
+    fn is_preset_disabled(&self, preset: &DateRangePreset, cx: &App) -> bool {
+        self.loading || preset.disabled || self.calendar.read(cx).is_date_disabled(&preset.date())
     }
 
     fn select_preset(
@@ -259,6 +1447,10 @@ impl DatePicker {
         window: &mut Window,
         cx: &mut Context<Self>,
     ) {
+        if self.is_preset_disabled(preset, cx) {
+            return;
+        }
+
         match preset.value {
             DateRangePresetValue::Single(single) => {
                 self.update_date(Date::Single(Some(single)), true, window, cx)
@@ -277,36 +1469,160 @@ impl Sizable for DatePicker {
         self
     }
 }
+
+impl Validatable for DatePicker {
+    /// The date is invalid if the range is incomplete or the date is disabled.
+    fn validate_field(
+        &mut self,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Result<(), SharedString> {
+        if matches!(self.date, Date::Range(start, end) if start.is_some() != end.is_some()) {
+            return Err(t!("Form.incomplete").into());
+        }
+        if self.calendar.read(cx).is_date_disabled(&self.date) {
+            return Err(t!("Form.date_disabled").into());
+        }
+
+        Ok(())
+    }
+}
+
 impl Focusable for DatePicker {
     fn focus_handle(&self, _: &App) -> FocusHandle {
         self.focus_handle.clone()
     }
 }
+impl FocusableCycle for DatePicker {
+    /// The input, and the presets, the calendar header buttons and the days in the popup.
+    fn cycle_focus_handles(&self, _: &mut Window, cx: &mut App) -> Vec<FocusHandle> {
+        let mut handles = vec![self.focus_handle.clone()];
+        if !self.open {
+            return handles;
+        }
+
+        match self.presets_dropdown.as_ref() {
+            Some(dropdown) => handles.push(dropdown.focus_handle(cx)),
+            None => handles.extend(
+                self.presets
+                    .iter()
+                    .flatten()
+                    .zip(&self.preset_focus_handles)
+                    .filter_map(|(preset, handle)| {
+                        (!self.is_preset_disabled(preset, cx)).then(|| handle.clone())
+                    }),
+            ),
+        }
+        handles.extend(self.calendar.read(cx).cycle_focus_handles());
+
+        // The input keeps its positive tab index in the popup, not to be skipped in the trap,
+        // the others follow the render order.
+        crate::focusable::tab_order(handles.into_iter().enumerate().map(|(ix, handle)| {
+            let tab_index = if ix == 0 {
+                self.tab_index.filter(|tab_index| *tab_index > 0)
+            } else {
+                None
+            };
+            (tab_index, handle)
+        }))
+    }
+}
+
+impl TabStop for DatePicker {
+    fn tab_stop_index(&self, _: &App) -> Option<isize> {
+        self.tab_index
+    }
+}
 
 impl Render for DatePicker {
     fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl gpui::IntoElement {
+        if std::mem::take(&mut self.autofocus) && !self.disabled {
+            crate::focusable::autofocus(&self.focus_handle, window, cx);
+        }
         // This for keep focus border style, when click on the popup.
         let is_focused = self.focus_handle.contains_focused(window, cx);
-        let show_clean = self.cleanable && self.date.is_some();
-        let placeholder = self
-            .placeholder
-            .clone()
-            .unwrap_or_else(|| t!("DatePicker.placeholder").into());
-        let display_title = self
-            .date
-            .format(&self.date_format)
-            .unwrap_or(placeholder.clone());
+        let show_clean = self.cleanable && !self.disabled && self.date.is_some();
+        let placeholder = match (&self.placeholder, &self.range_placeholders) {
+            (Some(placeholder), _) => placeholder.clone(),
+            (None, Some((start, end))) if matches!(self.date, Date::Range(_, _)) => {
+                format!("{}{}{}", start, self.range_separator, end).into()
+            }
+            _ => t!("DatePicker.placeholder").into(),
+        };
+        let presets_dropdown = self.presets_dropdown(window, cx);
+        let presets_len = self.presets.as_ref().map_or(0, |presets| presets.len());
+        self.preset_focus_handles
+            .resize_with(presets_len, || cx.focus_handle());
+        let footer = self.render_footer(cx);
+        let display_title = self.display_title();
+        let empty_content = if display_title.is_none() {
+            self.empty_content
+                .as_ref()
+                .map(|builder| builder(window, cx))
+        } else {
+            None
+        };
+        let placeholder_color = self
+            .placeholder_color
+            .unwrap_or(cx.theme().muted_foreground);
+        let rtl = crate::direction(cx).is_rtl();
+        let split = self.range_layout == RangeLayout::Split
+            && self.granularity == Granularity::Day
+            && matches!(self.date, Date::Range(_, _));
 
         self.calendar.update(cx, |view, cx| {
             view.set_size(self.size, window, cx);
+            view.set_density(self.density, window, cx);
+            view.set_fixed_rows(self.fixed_rows, window, cx);
+            view.set_selection_shape(self.selection_shape, window, cx);
+            view.set_quick_jump(self.quick_jump, window, cx);
             view.set_number_of_months(self.number_of_months, window, cx);
+            view.set_independent_panes(self.independent_panes, window, cx);
+            view.set_orientation(self.orientation, window, cx);
+            view.set_loading(self.loading, window, cx);
+            view.set_label_format(self.date_format.clone(), window, cx);
+            view.set_max_selections(self.max_selections, window, cx);
         });
+        let selection_count = self
+            .max_selections
+            .filter(|_| self.date.is_multiple())
+            .map(|max| {
+                SharedString::from(t!(
+                    "DatePicker.selection_count",
+                    count = self.date.count(),
+                    max = max
+                ))
+            });
+        let accessible_label = self.accessible_label();
 
         div()
             .id(self.id.clone())
             .key_context("DatePicker")
-            .track_focus(&self.focus_handle)
-            .when(self.open, |this| this.on_action(cx.listener(Self::escape)))
+            .when(!self.disabled, |this| this.track_focus(&self.focus_handle))
+            .on_action(cx.listener(Self::escape))
+            .on_action(cx.listener(Self::tab))
+            .on_key_down(cx.listener(Self::on_key_down))
+            .on_action(cx.listener(Self::tab_prev))
+            .on_action(cx.listener(Self::on_action_clear))
+            .on_action(cx.listener(Self::prev_month))
+            .on_action(cx.listener(Self::next_month))
+            .on_action(cx.listener(Self::prev_year))
+            .on_action(cx.listener(Self::next_year))
+            .on_action(cx.listener(|this, action: &ExtendPrevDay, window, cx| {
+                this.forward_to_calendar(action, Calendar::on_action_extend_prev_day, window, cx)
+            }))
+            .on_action(cx.listener(|this, action: &ExtendNextDay, window, cx| {
+                this.forward_to_calendar(action, Calendar::on_action_extend_next_day, window, cx)
+            }))
+            .on_action(cx.listener(|this, action: &ExtendPrevWeek, window, cx| {
+                this.forward_to_calendar(action, Calendar::on_action_extend_prev_week, window, cx)
+            }))
+            .on_action(cx.listener(|this, action: &ExtendNextWeek, window, cx| {
+                this.forward_to_calendar(action, Calendar::on_action_extend_next_week, window, cx)
+            }))
+            .on_action(cx.listener(|this, action: &ConfirmRange, window, cx| {
+                this.forward_to_calendar(action, Calendar::on_action_confirm_range, window, cx)
+            }))
             .w_full()
             .relative()
             .map(|this| match self.width {
@@ -322,6 +1638,7 @@ impl Render for DatePicker {
                     .items_center()
                     .justify_between()
                     .bg(cx.theme().background)
+                    .when(self.disabled, |this| this.disabled_style(cx))
                     .border_1()
                     .border_color(cx.theme().input)
                     .rounded(cx.theme().radius)
@@ -329,9 +1646,13 @@ impl Render for DatePicker {
                     .overflow_hidden()
                     .input_text_size(self.size)
                     .when(is_focused, |this| this.focused_border(cx))
-                    .input_size(self.size)
-                    .when(!self.open, |this| {
-                        this.on_click(cx.listener(Self::toggle_calendar))
+                    .input_size_with_density(self.size, cx.theme().density)
+                    .when(!self.open && !self.disabled, |this| {
+                        this.on_click(cx.listener(Self::toggle_calendar)).tooltip(
+                            move |window, cx| {
+                                Tooltip::new(accessible_label.clone()).build(window, cx)
+                            },
+                        )
                     })
                     .child(
                         h_flex()
@@ -339,9 +1660,69 @@ impl Render for DatePicker {
                             .items_center()
                             .justify_between()
                             .gap_1()
-                            .child(div().w_full().overflow_hidden().child(display_title))
+                            .when(rtl, |this| this.flex_row_reverse())
+                            .when(split, |this| {
+                                this.child(
+                                    h_flex()
+                                        .w_full()
+                                        .gap_1()
+                                        .when(rtl, |this| this.flex_row_reverse())
+                                        .child(self.render_range_field(
+                                            RangeEndpoint::Start,
+                                            placeholder_color,
+                                            cx,
+                                        ))
+                                        .child(
+                                            div()
+                                                .flex_none()
+                                                .text_color(cx.theme().muted_foreground)
+                                                .child(self.range_separator.trim().to_string()),
+                                        )
+                                        .child(self.render_range_field(
+                                            RangeEndpoint::End,
+                                            placeholder_color,
+                                            cx,
+                                        )),
+                                )
+                            })
+                            .when(!split, |this| {
+                                this.child(
+                                    div()
+                                        .w_full()
+                                        .overflow_hidden()
+                                        .when(rtl, |this| this.text_right())
+                                        .when(display_title.is_none(), |this| {
+                                            this.text_color(placeholder_color)
+                                        })
+                                        .map(|this| match empty_content {
+                                            Some(content) => this.child(content),
+                                            None => {
+                                                this.child(display_title.unwrap_or(placeholder))
+                                            }
+                                        }),
+                                )
+                            })
+                            .when(show_clean && self.clear_confirming.is_some(), |this| {
+                                this.child(
+                                    div()
+                                        .flex_shrink_0()
+                                        .text_xs()
+                                        .text_color(cx.theme().danger)
+                                        .child(t!("DatePicker.confirm_clear").to_string()),
+                                )
+                            })
                             .when(show_clean, |this| {
-                                this.child(clear_button(cx).on_click(cx.listener(Self::clean)))
+                                this.child(
+                                    sized_clear_button(self.size, cx)
+                                        .tooltip_with_action(
+                                            t!("Common.clear"),
+                                            &Clear,
+                                            Some("DatePicker"),
+                                        )
+                                        .on_click(cx.listener(|this, _, window, cx| {
+                                            this.clean(window, cx)
+                                        })),
+                                )
                             })
                             .when(!show_clean, |this| {
                                 this.child(
@@ -362,13 +1743,23 @@ impl Render for DatePicker {
                                 .p_3()
                                 .border_1()
                                 .border_color(cx.theme().border)
-                                .shadow_lg()
-                                .rounded((cx.theme().radius * 2.).min(px(8.)))
+                                .when(self.popup_shadow, |this| this.shadow_lg())
+                                .rounded(
+                                    self.popup_radius
+                                        .unwrap_or((cx.theme().radius * 2.).min(px(8.))),
+                                )
                                 .bg(cx.theme().background)
                                 .on_mouse_up_out(
                                     MouseButton::Left,
                                     cx.listener(|view, _, window, cx| {
-                                        view.escape(&Cancel, window, cx);
+                                        // The menu of the presets dropdown is out of the popup.
+                                        let presets_open = view
+                                            .presets_dropdown
+                                            .as_ref()
+                                            .map_or(false, |dropdown| dropdown.read(cx).is_open());
+                                        if !presets_open {
+                                            view.escape(&Cancel, window, cx);
+                                        }
                                     }),
                                 )
                                 .child(
@@ -376,28 +1767,23 @@ impl Render for DatePicker {
                                         .gap_3()
                                         .h_full()
                                         .items_start()
-                                        .when_some(self.presets.clone(), |this, presets| {
-                                            this.child(
-                                                v_flex().my_1().gap_2().justify_end().children(
-                                                    presets.into_iter().enumerate().map(
-                                                        |(i, preset)| {
-                                                            Button::new(("preset", i))
-                                                                .small()
-                                                                .ghost()
-                                                                .label(preset.label.clone())
-                                                                .on_click(cx.listener(
-                                                                    move |this, _, window, cx| {
-                                                                        this.select_preset(
-                                                                            &preset, window, cx,
-                                                                        );
-                                                                    },
-                                                                ))
-                                                        },
-                                                    ),
-                                                ),
-                                            )
+                                        .when(rtl, |this| this.flex_row_reverse())
+                                        .when(presets_dropdown.is_none(), |this| {
+                                            this.children(self.render_preset_buttons(cx))
                                         })
-                                        .child(self.calendar.clone()),
+                                        .child(
+                                            v_flex()
+                                                .gap_2()
+                                                .children(presets_dropdown)
+                                                .child(self.calendar.clone())
+                                                .children(selection_count.map(|count| {
+                                                    div()
+                                                        .text_xs()
+                                                        .text_color(cx.theme().muted_foreground)
+                                                        .child(count)
+                                                }))
+                                                .children(footer),
+                                        ),
                                 ),
                         ),
                     )
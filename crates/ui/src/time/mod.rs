@@ -1,3 +1,5 @@
 pub mod calendar;
+pub mod date_label;
 pub mod date_picker;
+pub mod time_picker;
 mod utils;
@@ -0,0 +1,554 @@
+use chrono::{NaiveTime, Timelike};
+use gpui::{
+    anchored, deferred, div, prelude::FluentBuilder as _, px, App, AppContext, Context, ElementId,
+    Entity, EventEmitter, FocusHandle, Focusable, InteractiveElement as _, KeyBinding, Length,
+    MouseButton, ParentElement as _, Render, SharedString, StatefulInteractiveElement as _, Styled,
+    Subscription, Window,
+};
+use rust_i18n::t;
+
+use crate::{
+    actions::Cancel,
+    button::{Button, ButtonVariants as _},
+    h_flex,
+    input::{sized_clear_button, InputEvent, NumberInput, NumberInputEvent, StepAction},
+    ActiveTheme, Icon, IconName, Selectable as _, Sizable, Size, StyleSized as _, StyledExt as _,
+};
+
+pub fn init(cx: &mut App) {
+    let context = Some("TimePicker");
+    cx.bind_keys([KeyBinding::new("escape", Cancel, context)])
+}
+
+#[derive(Clone)]
+pub enum TimePickerEvent {
+    Change(Option<NaiveTime>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TimeField {
+    Hour,
+    Minute,
+    Second,
+}
+
+pub struct TimePicker {
+    id: ElementId,
+    focus_handle: FocusHandle,
+    time: Option<NaiveTime>,
+    cleanable: bool,
+    placeholder: Option<SharedString>,
+    open: bool,
+    size: Size,
+    width: Length,
+    /// Use 12-hour mode with AM/PM, default is `false`.
+    use_12_hour: bool,
+    show_seconds: bool,
+    minute_step: u32,
+    second_step: u32,
+    hour_input: Entity<NumberInput>,
+    minute_input: Entity<NumberInput>,
+    second_input: Entity<NumberInput>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl TimePicker {
+    /// Create a time picker.
+    pub fn new(id: impl Into<ElementId>, window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let hour_input = cx.new(|cx| {
+            NumberInput::new(window, cx)
+                .small()
+                .blur_on_escape(false, cx)
+        });
+        let minute_input = cx.new(|cx| {
+            NumberInput::new(window, cx)
+                .small()
+                .blur_on_escape(false, cx)
+        });
+        let second_input = cx.new(|cx| {
+            NumberInput::new(window, cx)
+                .small()
+                .blur_on_escape(false, cx)
+        });
+
+        let _subscriptions = vec![
+            Self::subscribe_field(&hour_input, TimeField::Hour, window, cx),
+            Self::subscribe_field(&minute_input, TimeField::Minute, window, cx),
+            Self::subscribe_field(&second_input, TimeField::Second, window, cx),
+        ];
+
+        let mut this = Self {
+            id: id.into(),
+            focus_handle: cx.focus_handle(),
+            time: None,
+            cleanable: false,
+            placeholder: None,
+            open: false,
+            size: Size::default(),
+            width: Length::Auto,
+            use_12_hour: false,
+            show_seconds: false,
+            minute_step: 1,
+            second_step: 1,
+            hour_input,
+            minute_input,
+            second_input,
+            _subscriptions,
+        };
+        this.sync_inputs(window, cx);
+        this
+    }
+
+    fn subscribe_field(
+        input: &Entity<NumberInput>,
+        field: TimeField,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Subscription {
+        cx.subscribe_in(
+            input,
+            window,
+            move |this, input, event: &NumberInputEvent, window, cx| match event {
+                NumberInputEvent::Step(action) => this.step(field, *action, window, cx),
+                NumberInputEvent::Input(InputEvent::Change(text)) => {
+                    // Skip the changes by `sync_inputs`, only handle the typing.
+                    if !input.focus_handle(cx).is_focused(window) {
+                        return;
+                    }
+
+                    if let Ok(value) = text.parse::<u32>() {
+                        this.input_field(field, value, window, cx);
+                    }
+                }
+                NumberInputEvent::Input(InputEvent::Blur) => this.sync_inputs(window, cx),
+                _ => {}
+            },
+        )
+    }
+
+    /// Set the placeholder of the time picker.
+    ///
+    /// If not set, the translated `TimePicker.placeholder` of the current locale is used.
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Set true to show the clear button when the input field is not empty.
+    pub fn cleanable(mut self) -> Self {
+        self.cleanable = true;
+        self
+    }
+
+    /// Set width of the time picker input field, default is `Length::Auto`.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Set true to use 12-hour mode with AM/PM, default is `false` (24-hour mode).
+    pub fn use_12_hour(mut self, use_12_hour: bool) -> Self {
+        self.use_12_hour = use_12_hour;
+        self
+    }
+
+    /// Set true to show the seconds spinner, default is `false`.
+    pub fn show_seconds(mut self, show_seconds: bool) -> Self {
+        self.show_seconds = show_seconds;
+        self
+    }
+
+    /// Set the step of the minute spinner, default is 1.
+    ///
+    /// For example, use `15` to step the minutes with 0, 15, 30, 45.
+    pub fn minute_step(mut self, step: u32) -> Self {
+        self.minute_step = step.clamp(1, 59);
+        self
+    }
+
+    /// Set the step of the second spinner, default is 1.
+    pub fn second_step(mut self, step: u32) -> Self {
+        self.second_step = step.clamp(1, 59);
+        self
+    }
+
+    /// Get the time of the time picker.
+    pub fn time(&self) -> Option<NaiveTime> {
+        self.time
+    }
+
+    /// Set the time of the time picker.
+    pub fn set_time(
+        &mut self,
+        time: impl Into<Option<NaiveTime>>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.update_time(time.into(), false, window, cx);
+    }
+
+    /// Set size of the time picker.
+    pub fn set_size(&mut self, size: Size, _: &mut Window, cx: &mut Context<Self>) {
+        self.size = size;
+        cx.notify();
+    }
+
+    fn update_time(
+        &mut self,
+        time: Option<NaiveTime>,
+        emit: bool,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.time = time;
+        self.sync_inputs(window, cx);
+        if emit {
+            cx.emit(TimePickerEvent::Change(time));
+        }
+        cx.notify();
+    }
+
+    /// Sync the time to the spinners text.
+    fn sync_inputs(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let time = self.time.unwrap_or_default();
+        let hour = if self.use_12_hour {
+            time.hour12().1
+        } else {
+            time.hour()
+        };
+
+        self.hour_input.update(cx, |input, cx| {
+            input.set_text(format!("{:02}", hour), window, cx);
+        });
+        self.minute_input.update(cx, |input, cx| {
+            input.set_text(format!("{:02}", time.minute()), window, cx);
+        });
+        self.second_input.update(cx, |input, cx| {
+            input.set_text(format!("{:02}", time.second()), window, cx);
+        });
+    }
+
+    fn step(
+        &mut self,
+        field: TimeField,
+        action: StepAction,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let time = self.time.unwrap_or_default();
+        let time = match field {
+            TimeField::Hour => time.with_hour(step_value(time.hour(), 1, 24, action)),
+            TimeField::Minute => {
+                time.with_minute(step_value(time.minute(), self.minute_step, 60, action))
+            }
+            TimeField::Second => {
+                time.with_second(step_value(time.second(), self.second_step, 60, action))
+            }
+        };
+
+        if let Some(time) = time {
+            self.update_time(Some(time), true, window, cx);
+        }
+    }
+
+    /// Update the field by the typed value, the text of the spinner will be kept until blur.
+    fn input_field(
+        &mut self,
+        field: TimeField,
+        value: u32,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let time = self.time.unwrap_or_default();
+        let time = match field {
+            TimeField::Hour if self.use_12_hour => {
+                if !(1..=12).contains(&value) {
+                    return;
+                }
+                time.with_hour(hour12_to_hour24(value, time.hour12().0))
+            }
+            TimeField::Hour => time.with_hour(value),
+            TimeField::Minute => time.with_minute(value),
+            TimeField::Second => time.with_second(value),
+        };
+
+        if let Some(time) = time {
+            if self.time != Some(time) {
+                self.time = Some(time);
+                cx.emit(TimePickerEvent::Change(self.time));
+                cx.notify();
+            }
+        }
+    }
+
+    fn set_pm(&mut self, pm: bool, window: &mut Window, cx: &mut Context<Self>) {
+        let time = self.time.unwrap_or_default();
+        if time.hour12().0 == pm {
+            return;
+        }
+
+        let hour = hour12_to_hour24(time.hour12().1, pm);
+        if let Some(time) = time.with_hour(hour) {
+            self.update_time(Some(time), true, window, cx);
+        }
+    }
+
+    fn format(&self) -> &'static str {
+        match (self.use_12_hour, self.show_seconds) {
+            (true, true) => "%I:%M:%S %p",
+            (true, false) => "%I:%M %p",
+            (false, true) => "%H:%M:%S",
+            (false, false) => "%H:%M",
+        }
+    }
+
+    fn escape(&mut self, _: &Cancel, window: &mut Window, cx: &mut Context<Self>) {
+        if !self.open {
+            window.blur();
+            cx.propagate();
+            return;
+        }
+
+        self.focus_back_if_need(window, cx);
+        self.open = false;
+
+        cx.notify();
+    }
+
+    // To focus the Picker Input, if current focus in is on the popup.
+    fn focus_back_if_need(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if !self.open {
+            return;
+        }
+
+        if let Some(focused) = window.focused(cx) {
+            if focused.contains(&self.focus_handle, window) {
+                self.focus_handle.focus(window);
+            }
+        }
+    }
+
+    fn clean(&mut self, _: &gpui::ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        self.update_time(None, true, window, cx);
+    }
+
+    fn toggle_popup(&mut self, _: &gpui::ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        self.open = !self.open;
+        if self.open {
+            self.sync_inputs(window, cx);
+        }
+        cx.notify();
+    }
+}
+
+/// Step the value by the step, wrap around in `0..max`.
+///
+/// The value will be snapped to the multiple of the step.
+fn step_value(value: u32, step: u32, max: u32, action: StepAction) -> u32 {
+    let step = step.max(1);
+    match action {
+        StepAction::Increment => {
+            let value = (value / step + 1) * step;
+            if value >= max {
+                0
+            } else {
+                value
+            }
+        }
+        StepAction::Decrement => {
+            if value == 0 {
+                (max - 1) / step * step
+            } else {
+                (value - 1) / step * step
+            }
+        }
+    }
+}
+
+/// Convert 12-hour (1..=12) to 24-hour (0..24).
+fn hour12_to_hour24(hour: u32, pm: bool) -> u32 {
+    match (hour % 12, pm) {
+        (hour, false) => hour,
+        (hour, true) => hour + 12,
+    }
+}
+
+impl EventEmitter<TimePickerEvent> for TimePicker {}
+impl Sizable for TimePicker {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+impl Focusable for TimePicker {
+    fn focus_handle(&self, _: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for TimePicker {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl gpui::IntoElement {
+        // This for keep focus border style, when click on the popup.
+        let is_focused = self.focus_handle.contains_focused(window, cx);
+        let show_clean = self.cleanable && self.time.is_some();
+        let placeholder = self
+            .placeholder
+            .clone()
+            .unwrap_or_else(|| t!("TimePicker.placeholder").into());
+        let display_title = self
+            .time
+            .map(|time| SharedString::from(time.format(self.format()).to_string()));
+        let is_pm = self.time.unwrap_or_default().hour12().0;
+
+        div()
+            .id(self.id.clone())
+            .key_context("TimePicker")
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::escape))
+            .w_full()
+            .relative()
+            .map(|this| match self.width {
+                Length::Definite(l) => this.flex_none().w(l),
+                Length::Auto => this.w_full(),
+            })
+            .input_text_size(self.size)
+            .child(
+                div()
+                    .id("time-picker-input")
+                    .relative()
+                    .flex()
+                    .items_center()
+                    .justify_between()
+                    .bg(cx.theme().background)
+                    .border_1()
+                    .border_color(cx.theme().input)
+                    .rounded(cx.theme().radius)
+                    .when(cx.theme().shadow, |this| this.shadow_sm())
+                    .overflow_hidden()
+                    .input_text_size(self.size)
+                    .when(is_focused, |this| this.focused_border(cx))
+                    .input_size_with_density(self.size, cx.theme().density)
+                    .when(!self.open, |this| {
+                        this.on_click(cx.listener(Self::toggle_popup))
+                    })
+                    .child(
+                        h_flex()
+                            .w_full()
+                            .items_center()
+                            .justify_between()
+                            .gap_1()
+                            .child(
+                                div()
+                                    .w_full()
+                                    .overflow_hidden()
+                                    .when(display_title.is_none(), |this| {
+                                        this.text_color(cx.theme().muted_foreground)
+                                    })
+                                    .child(display_title.unwrap_or(placeholder)),
+                            )
+                            .when(show_clean, |this| {
+                                this.child(
+                                    sized_clear_button(self.size, cx)
+                                        .on_click(cx.listener(Self::clean)),
+                                )
+                            })
+                            .when(!show_clean, |this| {
+                                this.child(
+                                    Icon::new(IconName::Clock)
+                                        .xsmall()
+                                        .text_color(cx.theme().muted_foreground),
+                                )
+                            }),
+                    ),
+            )
+            .when(self.open, |this| {
+                this.child(
+                    deferred(
+                        anchored().snap_to_window_with_margin(px(8.)).child(
+                            h_flex()
+                                .occlude()
+                                .mt_1p5()
+                                .p_3()
+                                .gap_1()
+                                .border_1()
+                                .border_color(cx.theme().border)
+                                .shadow_lg()
+                                .rounded((cx.theme().radius * 2.).min(px(8.)))
+                                .bg(cx.theme().background)
+                                .on_mouse_up_out(
+                                    MouseButton::Left,
+                                    cx.listener(|view, _, window, cx| {
+                                        view.escape(&Cancel, window, cx);
+                                    }),
+                                )
+                                .child(div().w(px(96.)).child(self.hour_input.clone()))
+                                .child(":")
+                                .child(div().w(px(96.)).child(self.minute_input.clone()))
+                                .when(self.show_seconds, |this| {
+                                    this.child(":")
+                                        .child(div().w(px(96.)).child(self.second_input.clone()))
+                                })
+                                .when(self.use_12_hour, |this| {
+                                    this.child(
+                                        h_flex()
+                                            .ml_2()
+                                            .gap_1()
+                                            .child(
+                                                Button::new("am")
+                                                    .small()
+                                                    .ghost()
+                                                    .label(t!("TimePicker.am"))
+                                                    .selected(!is_pm)
+                                                    .on_click(cx.listener(
+                                                        |this, _, window, cx| {
+                                                            this.set_pm(false, window, cx)
+                                                        },
+                                                    )),
+                                            )
+                                            .child(
+                                                Button::new("pm")
+                                                    .small()
+                                                    .ghost()
+                                                    .label(t!("TimePicker.pm"))
+                                                    .selected(is_pm)
+                                                    .on_click(cx.listener(
+                                                        |this, _, window, cx| {
+                                                            this.set_pm(true, window, cx)
+                                                        },
+                                                    )),
+                                            ),
+                                    )
+                                }),
+                        ),
+                    )
+                    .with_priority(2),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{hour12_to_hour24, step_value};
+    use crate::input::StepAction;
+
+    #[test]
+    fn test_step_value() {
+        assert_eq!(step_value(7, 15, 60, StepAction::Increment), 15);
+        assert_eq!(step_value(7, 15, 60, StepAction::Decrement), 0);
+        assert_eq!(step_value(15, 15, 60, StepAction::Decrement), 0);
+        assert_eq!(step_value(45, 15, 60, StepAction::Increment), 0);
+        assert_eq!(step_value(0, 15, 60, StepAction::Decrement), 45);
+        assert_eq!(step_value(23, 1, 24, StepAction::Increment), 0);
+        assert_eq!(step_value(0, 1, 24, StepAction::Decrement), 23);
+        assert_eq!(step_value(10, 0, 24, StepAction::Increment), 11);
+    }
+
+    #[test]
+    fn test_hour12_to_hour24() {
+        assert_eq!(hour12_to_hour24(12, false), 0);
+        assert_eq!(hour12_to_hour24(1, false), 1);
+        assert_eq!(hour12_to_hour24(12, true), 12);
+        assert_eq!(hour12_to_hour24(11, true), 23);
+    }
+}
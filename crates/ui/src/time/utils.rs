@@ -1,4 +1,4 @@
-use chrono::{Datelike, Duration, NaiveDate};
+use chrono::{Datelike, Duration, Months, NaiveDate};
 
 trait NaiveDateExt {
     fn days_in_month(&self) -> i32;
@@ -28,7 +28,10 @@ impl NaiveDateExt for chrono::NaiveDate {
     }
 }
 
-pub(crate) fn days_in_month(year: i32, month: u32) -> Vec<Vec<NaiveDate>> {
+/// Returns the weeks of the month from Sunday, padded with the days of the adjacent months.
+///
+/// There are 4 to 6 weeks to hold the days of the month, or always 6 weeks if `fixed_rows`.
+pub(crate) fn days_in_month(year: i32, month: u32, fixed_rows: bool) -> Vec<Vec<NaiveDate>> {
     let mut year = year;
     let mut month = month;
     if month > 12 {
@@ -42,7 +45,12 @@ pub(crate) fn days_in_month(year: i32, month: u32) -> Vec<Vec<NaiveDate>> {
 
     let date = NaiveDate::from_ymd_opt(year, month, 1).unwrap();
     let num_days = date.days_in_month();
-    let start_weekday = date.weekday().num_days_from_sunday();
+    let start_weekday = date.weekday().num_days_from_sunday() as i32;
+    let rows = if fixed_rows {
+        6
+    } else {
+        (start_weekday + num_days + 6) / 7
+    };
 
     // Get the days in the month, 2023-02 will returns
     // "29|30|31| 1| 2| 3| 4",
@@ -50,46 +58,450 @@ pub(crate) fn days_in_month(year: i32, month: u32) -> Vec<Vec<NaiveDate>> {
     // "12|13|14|15|16|17|18",
     // "19|20|21|22|23|24|25",
     // "26|27|28| 1| 2| 3| 4",
-    let mut days = vec![];
-    for n in 0..5 {
-        let mut week_days = vec![];
-        for weekday in 0..7 {
-            let (mut y, mut m) = (year, month);
-
-            // If the day is less than the start weekday, we need to go back to the previous month.
-            if n == 0 && weekday < start_weekday {
-                m = if m == 1 { 12 } else { m - 1 };
-                y = if m == 1 { year - 1 } else { y };
-            }
+    (0..rows)
+        .map(|n| {
+            (0..7)
+                .map(|weekday| {
+                    // If start_weekday is 3, and n is 0 and weekday is 3, then day is 0 (the 1st).
+                    let day = n * 7 + weekday - start_weekday;
 
-            // If start_weekday is 3, and n is 0 and weekday is 3, then day is 1.
-            // If start_weekday is 3, and n is 1 and weekday is 4, then day is 9.
-            let day = n * 7 + weekday as i32 - start_weekday as i32;
+                    #[allow(clippy::expect_fun_call)]
+                    date.checked_add_signed(Duration::days(day as i64))
+                        .expect(&format!("invalid date {}-{} days {}", year, month, day))
+                })
+                .collect()
+        })
+        .collect()
+}
 
-            // If the day is greater than the number of days in the month, we need to go to the next month.
-            if day > num_days {
-                m = if m == 12 { 1 } else { m + 1 };
-                y = if m == 1 { year + 1 } else { y };
-            }
+/// Returns the first and last day of the quarter (1..=4) in the year.
+pub(crate) fn quarter_range(year: i32, quarter: u32) -> Option<(NaiveDate, NaiveDate)> {
+    if !(1..=4).contains(&quarter) {
+        return None;
+    }
+
+    let start = NaiveDate::from_ymd_opt(year, (quarter - 1) * 3 + 1, 1)?;
+    let end = if quarter == 4 {
+        NaiveDate::from_ymd_opt(year, 12, 31)?
+    } else {
+        NaiveDate::from_ymd_opt(year, quarter * 3 + 1, 1)?.pred_opt()?
+    };
+
+    Some((start, end))
+}
+
+/// Returns the first (Sunday) and last (Saturday) day of the week that contains the date.
+pub(crate) fn week_range(date: &NaiveDate) -> Option<(NaiveDate, NaiveDate)> {
+    let start =
+        date.checked_sub_signed(Duration::days(date.weekday().num_days_from_sunday() as i64))?;
+    Some((start, start.checked_add_signed(Duration::days(6))?))
+}
+
+/// Returns the first and last day of the year.
+pub(crate) fn year_range(year: i32) -> Option<(NaiveDate, NaiveDate)> {
+    Some((
+        NaiveDate::from_ymd_opt(year, 1, 1)?,
+        NaiveDate::from_ymd_opt(year, 12, 31)?,
+    ))
+}
+
+/// Returns the quarter (1..=4) of the date.
+pub(crate) fn quarter_of(date: &NaiveDate) -> u32 {
+    (date.month() - 1) / 3 + 1
+}
+
+/// Parse the date text with the formats, the first matched format is used.
+///
+/// The two-digit year (`%y`) is resolved to 1970..=2069.
+pub(crate) fn parse_date(text: &str, formats: &[impl AsRef<str>]) -> Option<NaiveDate> {
+    let text = text.trim();
+    if text.is_empty() {
+        return None;
+    }
+
+    formats
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(text, format.as_ref()).ok())
+}
+
+/// The common separators of the date range text, tried after the configured separator.
+pub(crate) const RANGE_SEPARATORS: [&str; 5] = [" - ", " ~ ", "~", " to ", " – "];
+
+/// Parse the date range text, split by the first separator contained in the text.
+///
+/// Returns the start and end that can be parsed, swapped if the start is after the end.
+/// If no separator is found, the whole text is parsed as the start.
+pub(crate) fn parse_date_range(
+    text: &str,
+    separators: &[&str],
+    formats: &[impl AsRef<str>],
+) -> (Option<NaiveDate>, Option<NaiveDate>) {
+    let Some((start, end)) = separators
+        .iter()
+        .filter(|separator| !separator.is_empty())
+        .find_map(|separator| text.split_once(separator))
+    else {
+        return (parse_date(text, formats), None);
+    };
+
+    match (parse_date(start, formats), parse_date(end, formats)) {
+        (Some(start), Some(end)) if start > end => (Some(end), Some(start)),
+        range => range,
+    }
+}
+
+/// Parse the relative offset from the `today`, e.g.: `+3d`, `-1w`, `+2m`, `+1y`.
+///
+/// The units are `d` (days), `w` (weeks), `m` (months) and `y` (years), case-insensitive.
+pub(crate) fn parse_relative_offset(text: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let text = text.trim();
+    let (sign, rest) = match text.chars().next()? {
+        '+' => (1, &text[1..]),
+        '-' => (-1, &text[1..]),
+        _ => return None,
+    };
+    let (ix, unit) = rest.char_indices().last()?;
+    let count = rest[..ix].trim().parse::<u32>().ok()?;
+
+    match (unit.to_ascii_lowercase(), sign) {
+        ('d', _) => today.checked_add_signed(Duration::days(sign * count as i64)),
+        ('w', _) => today.checked_add_signed(Duration::weeks(sign * count as i64)),
+        ('m', 1) => today.checked_add_months(Months::new(count)),
+        ('m', _) => today.checked_sub_months(Months::new(count)),
+        ('y', 1) => today.checked_add_months(Months::new(count.checked_mul(12)?)),
+        ('y', _) => today.checked_sub_months(Months::new(count.checked_mul(12)?)),
+        _ => None,
+    }
+}
+
+/// The localized keywords of the relative dates, each keyword may have multiple aliases.
+///
+/// The keywords are matched case-insensitive, and the empty aliases are ignored.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RelativeKeywords {
+    pub(crate) today: Vec<String>,
+    pub(crate) tomorrow: Vec<String>,
+    pub(crate) yesterday: Vec<String>,
+    /// The prefix of the next weekday, e.g. `next monday`.
+    pub(crate) next: Vec<String>,
+    /// The prefix of the last weekday, e.g. `last friday`.
+    pub(crate) last: Vec<String>,
+    /// The prefix of the future offset, e.g. `in 2 weeks`.
+    pub(crate) in_: Vec<String>,
+    /// The suffix of the future offset, e.g. `3 days later`.
+    pub(crate) later: Vec<String>,
+    /// The suffix of the past offset, e.g. `3 days ago`.
+    pub(crate) ago: Vec<String>,
+    /// The units of days, weeks, months and years.
+    pub(crate) units: [Vec<String>; 4],
+    /// The weekdays from Sunday.
+    pub(crate) weekdays: [Vec<String>; 7],
+}
 
-            #[allow(clippy::expect_fun_call)]
-            let date = date
-                .checked_add_signed(Duration::days(day as i64))
-                .expect(&format!("invalid date {}-{} days {}", y, m, day));
-            week_days.push(date);
+impl RelativeKeywords {
+    /// Split the comma separated aliases, e.g. `day,days`, the longer aliases are matched first.
+    pub(crate) fn aliases(text: &str) -> Vec<String> {
+        let mut aliases = text
+            .split(',')
+            .map(|alias| alias.trim().to_lowercase())
+            .filter(|alias| !alias.is_empty())
+            .collect::<Vec<_>>();
+        aliases.sort_by_key(|alias| std::cmp::Reverse(alias.chars().count()));
+        aliases
+    }
+}
+
+fn strip_prefix<'a>(text: &'a str, aliases: &[String]) -> Option<&'a str> {
+    aliases
+        .iter()
+        .find_map(|alias| text.strip_prefix(alias.as_str()))
+        .map(str::trim)
+}
+
+fn strip_suffix<'a>(text: &'a str, aliases: &[String]) -> Option<&'a str> {
+    aliases
+        .iter()
+        .find_map(|alias| text.strip_suffix(alias.as_str()))
+        .map(str::trim)
+}
+
+/// Parse the relative date from the `today`, e.g.: `tomorrow`, `next monday`, `in 2 weeks`,
+/// `3 days ago`, and the offsets of [`parse_relative_offset`] like `+3d`.
+pub(crate) fn parse_relative_date(
+    text: &str,
+    today: NaiveDate,
+    keywords: &RelativeKeywords,
+) -> Option<NaiveDate> {
+    if let Some(date) = parse_relative_offset(text, today) {
+        return Some(date);
+    }
+
+    let text = text.trim().to_lowercase();
+    for (aliases, days) in [
+        (&keywords.today, 0),
+        (&keywords.tomorrow, 1),
+        (&keywords.yesterday, -1),
+    ] {
+        if aliases.contains(&text) {
+            return today.checked_add_signed(Duration::days(days));
         }
+    }
 
-        days.push(week_days);
+    let weekday_of = |text: &str| {
+        keywords
+            .weekdays
+            .iter()
+            .position(|aliases| aliases.iter().any(|alias| alias == text))
+            .map(|ix| ix as i64)
+    };
+    let current = today.weekday().num_days_from_sunday() as i64;
+    if let Some(weekday) = strip_prefix(&text, &keywords.next).and_then(weekday_of) {
+        let days = (weekday - current).rem_euclid(7);
+        return today.checked_add_signed(Duration::days(if days == 0 { 7 } else { days }));
     }
+    if let Some(weekday) = strip_prefix(&text, &keywords.last).and_then(weekday_of) {
+        let days = (current - weekday).rem_euclid(7);
+        return today.checked_sub_signed(Duration::days(if days == 0 { 7 } else { days }));
+    }
+
+    let (sign, rest) = if let Some(rest) = strip_prefix(&text, &keywords.in_) {
+        ('+', rest)
+    } else if let Some(rest) = strip_suffix(&text, &keywords.later) {
+        ('+', rest)
+    } else if let Some(rest) = strip_suffix(&text, &keywords.ago) {
+        ('-', rest)
+    } else {
+        return None;
+    };
+    let ix = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+    let (count, unit) = (&rest[..ix], rest[ix..].trim());
+    let unit = keywords
+        .units
+        .iter()
+        .position(|aliases| aliases.iter().any(|alias| alias == unit))?;
 
-    days
+    parse_relative_offset(
+        &format!("{}{}{}", sign, count, ['d', 'w', 'm', 'y'][unit]),
+        today,
+    )
+}
+
+/// Returns the day of the month by the typed day number, clamped to the days of the month.
+pub(crate) fn typeahead_day(query: &str, year: i32, month: u32) -> Option<u32> {
+    let day = query.parse::<u32>().ok().filter(|day| *day > 0)?;
+    let days = NaiveDate::from_ymd_opt(year, month, 1)?.days_in_month() as u32;
+    Some(day.min(days))
+}
+
+/// Returns the index of the first item starting with the query (case-insensitive), for typeahead.
+pub(crate) fn typeahead_match(items: &[impl AsRef<str>], query: &str) -> Option<usize> {
+    if query.is_empty() {
+        return None;
+    }
+
+    let query = query.to_lowercase();
+    items
+        .iter()
+        .position(|item| item.as_ref().to_lowercase().starts_with(&query))
 }
 
 #[cfg(test)]
 mod tests {
     use chrono::{Datelike, NaiveDate};
 
-    use super::{days_in_month, NaiveDateExt};
+    use super::{
+        days_in_month, parse_date, parse_date_range, parse_relative_date, parse_relative_offset,
+        quarter_of, quarter_range, typeahead_day, typeahead_match, week_range, year_range,
+        NaiveDateExt, RelativeKeywords, RANGE_SEPARATORS,
+    };
+
+    #[test]
+    fn test_parse_relative_offset() {
+        let today = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d);
+
+        assert_eq!(parse_relative_offset("+3d", today), date(2024, 2, 3));
+        assert_eq!(parse_relative_offset("-1D", today), date(2024, 1, 30));
+        assert_eq!(parse_relative_offset("+2w", today), date(2024, 2, 14));
+        assert_eq!(parse_relative_offset(" +1m ", today), date(2024, 2, 29));
+        assert_eq!(parse_relative_offset("-2m", today), date(2023, 11, 30));
+        assert_eq!(parse_relative_offset("+1y", today), date(2025, 1, 31));
+        assert_eq!(parse_relative_offset("+ 10 d", today), date(2024, 2, 10));
+
+        assert_eq!(parse_relative_offset("3d", today), None);
+        assert_eq!(parse_relative_offset("+d", today), None);
+        assert_eq!(parse_relative_offset("+3x", today), None);
+        assert_eq!(parse_relative_offset("+", today), None);
+        assert_eq!(parse_relative_offset("", today), None);
+        assert_eq!(parse_relative_offset("+3日", today), None);
+    }
+
+    #[test]
+    fn test_parse_relative_date() {
+        let aliases = RelativeKeywords::aliases;
+        let keywords = RelativeKeywords {
+            today: aliases("today"),
+            tomorrow: aliases("tomorrow"),
+            yesterday: aliases("yesterday"),
+            next: aliases("next"),
+            last: aliases("last"),
+            in_: aliases("in"),
+            later: aliases("later"),
+            ago: aliases("ago"),
+            units: [
+                aliases("day,days"),
+                aliases("week,weeks"),
+                aliases("month,months"),
+                aliases("year,years"),
+            ],
+            weekdays: [
+                aliases("sunday,sun"),
+                aliases("monday,mon"),
+                aliases("tuesday,tue"),
+                aliases("wednesday,wed"),
+                aliases("thursday,thu"),
+                aliases("friday,fri"),
+                aliases("saturday,sat"),
+            ],
+        };
+        // Wednesday
+        let today = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d);
+        let parse = |text| parse_relative_date(text, today, &keywords);
+
+        assert_eq!(parse("today"), date(2024, 1, 31));
+        assert_eq!(parse(" Tomorrow "), date(2024, 2, 1));
+        assert_eq!(parse("yesterday"), date(2024, 1, 30));
+        assert_eq!(parse("next monday"), date(2024, 2, 5));
+        assert_eq!(parse("next wed"), date(2024, 2, 7));
+        assert_eq!(parse("last friday"), date(2024, 1, 26));
+        assert_eq!(parse("last wednesday"), date(2024, 1, 24));
+        assert_eq!(parse("in 2 weeks"), date(2024, 2, 14));
+        assert_eq!(parse("in 1 month"), date(2024, 2, 29));
+        assert_eq!(parse("3 days later"), date(2024, 2, 3));
+        assert_eq!(parse("1 year ago"), date(2023, 1, 31));
+        assert_eq!(parse("+3d"), date(2024, 2, 3));
+        assert_eq!(
+            RelativeKeywords::aliases("下, 下个,"),
+            vec!["下个".to_string(), "下".to_string()]
+        );
+
+        assert_eq!(parse("next"), None);
+        assert_eq!(parse("in weeks"), None);
+        assert_eq!(parse("in 2 fortnights"), None);
+        assert_eq!(parse("2024/01/31"), None);
+        assert_eq!(parse(""), None);
+    }
+
+    #[test]
+    fn test_typeahead_day() {
+        assert_eq!(typeahead_day("1", 2024, 2), Some(1));
+        assert_eq!(typeahead_day("15", 2024, 2), Some(15));
+        assert_eq!(typeahead_day("31", 2024, 2), Some(29));
+        assert_eq!(typeahead_day("31", 2023, 2), Some(28));
+        assert_eq!(typeahead_day("35", 2024, 1), Some(31));
+        assert_eq!(typeahead_day("0", 2024, 1), None);
+        assert_eq!(typeahead_day("", 2024, 1), None);
+    }
+
+    #[test]
+    fn test_typeahead_match() {
+        let years = (1980..2020).map(|y| y.to_string()).collect::<Vec<_>>();
+        assert_eq!(typeahead_match(&years, "1"), Some(0));
+        assert_eq!(typeahead_match(&years, "199"), Some(10));
+        assert_eq!(typeahead_match(&years, "2015"), Some(35));
+        assert_eq!(typeahead_match(&years, "2025"), None);
+        assert_eq!(typeahead_match(&years, ""), None);
+
+        let months = ["January", "February", "March", "April", "May", "June"];
+        assert_eq!(typeahead_match(&months, "ma"), Some(2));
+        assert_eq!(typeahead_match(&months, "MAY"), Some(4));
+        assert_eq!(typeahead_match(&months, "ju"), Some(5));
+    }
+
+    #[test]
+    fn test_parse_date() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let formats = ["%m/%d/%y", "%Y/%m/%d", "%Y-%m-%d"];
+
+        assert_eq!(parse_date("3/5/24", &formats), Some(date(2024, 3, 5)));
+        assert_eq!(parse_date("03/05/99", &formats), Some(date(1999, 3, 5)));
+        assert_eq!(parse_date(" 2024/03/05 ", &formats), Some(date(2024, 3, 5)));
+        assert_eq!(parse_date("2024-3-5", &formats), Some(date(2024, 3, 5)));
+        assert_eq!(parse_date("2024/02/30", &formats), None);
+        assert_eq!(parse_date("foo", &formats), None);
+        assert_eq!(parse_date("", &formats), None);
+    }
+
+    #[test]
+    fn test_parse_date_range() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let formats = ["%Y/%m/%d", "%Y-%m-%d"];
+        let separators = [&[" ~ "][..], &RANGE_SEPARATORS[..]].concat();
+        let parse = |text| parse_date_range(text, &separators, &formats);
+
+        assert_eq!(
+            parse("2024/01/01 - 2024/01/31"),
+            (Some(date(2024, 1, 1)), Some(date(2024, 1, 31)))
+        );
+        assert_eq!(
+            parse("2024-01-01~2024-01-31"),
+            (Some(date(2024, 1, 1)), Some(date(2024, 1, 31)))
+        );
+        assert_eq!(
+            parse("2024/01/31 to 2024/01/01"),
+            (Some(date(2024, 1, 1)), Some(date(2024, 1, 31)))
+        );
+        assert_eq!(parse("2024/01/01 - foo"), (Some(date(2024, 1, 1)), None));
+        assert_eq!(parse("foo - 2024/01/31"), (None, Some(date(2024, 1, 31))));
+        assert_eq!(parse("2024-01-01"), (Some(date(2024, 1, 1)), None));
+        assert_eq!(parse("foo"), (None, None));
+    }
+
+    #[test]
+    fn test_quarter_range() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert_eq!(
+            quarter_range(2024, 1),
+            Some((date(2024, 1, 1), date(2024, 3, 31)))
+        );
+        assert_eq!(
+            quarter_range(2024, 2),
+            Some((date(2024, 4, 1), date(2024, 6, 30)))
+        );
+        assert_eq!(
+            quarter_range(2024, 4),
+            Some((date(2024, 10, 1), date(2024, 12, 31)))
+        );
+        assert_eq!(quarter_range(2024, 0), None);
+        assert_eq!(quarter_range(2024, 5), None);
+        assert_eq!(
+            year_range(2024),
+            Some((date(2024, 1, 1), date(2024, 12, 31)))
+        );
+
+        // 2024-08-04 is Sunday.
+        assert_eq!(
+            week_range(&date(2024, 8, 4)),
+            Some((date(2024, 8, 4), date(2024, 8, 10)))
+        );
+        assert_eq!(
+            week_range(&date(2024, 8, 7)),
+            Some((date(2024, 8, 4), date(2024, 8, 10)))
+        );
+        assert_eq!(
+            week_range(&date(2025, 1, 1)),
+            Some((date(2024, 12, 29), date(2025, 1, 4)))
+        );
+
+        assert_eq!(quarter_of(&date(2024, 1, 1)), 1);
+        assert_eq!(quarter_of(&date(2024, 3, 31)), 1);
+        assert_eq!(quarter_of(&date(2024, 7, 15)), 3);
+        assert_eq!(quarter_of(&date(2024, 12, 31)), 4);
+    }
 
     #[test]
     fn test_days_in_month() {
@@ -115,7 +527,12 @@ mod tests {
     fn test_days() {
         #[track_caller]
         fn assert_case(date: NaiveDate, expected: Vec<&str>) {
-            let out = days_in_month(date.year(), date.month())
+            assert_rows(date, false, expected);
+        }
+
+        #[track_caller]
+        fn assert_rows(date: NaiveDate, fixed_rows: bool, expected: Vec<&str>) {
+            let out = days_in_month(date.year(), date.month(), fixed_rows)
                 .iter()
                 .map(|week| {
                     week.iter()
@@ -177,5 +594,39 @@ mod tests {
                 "26|27|28|3-1|3-2|3-3|3-4",
             ],
         );
+        // 6 weeks to hold the 31st.
+        assert_case(
+            NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+            vec![
+                "2-25|2-26|2-27|2-28|2-29| 1| 2",
+                " 3| 4| 5| 6| 7| 8| 9",
+                "10|11|12|13|14|15|16",
+                "17|18|19|20|21|22|23",
+                "24|25|26|27|28|29|30",
+                "31|4-1|4-2|4-3|4-4|4-5|4-6",
+            ],
+        );
+        // 4 weeks from Sunday to Saturday.
+        assert_case(
+            NaiveDate::from_ymd_opt(2026, 2, 1).unwrap(),
+            vec![
+                " 1| 2| 3| 4| 5| 6| 7",
+                " 8| 9|10|11|12|13|14",
+                "15|16|17|18|19|20|21",
+                "22|23|24|25|26|27|28",
+            ],
+        );
+        assert_rows(
+            NaiveDate::from_ymd_opt(2026, 2, 1).unwrap(),
+            true,
+            vec![
+                " 1| 2| 3| 4| 5| 6| 7",
+                " 8| 9|10|11|12|13|14",
+                "15|16|17|18|19|20|21",
+                "22|23|24|25|26|27|28",
+                "3-1|3-2|3-3|3-4|3-5|3-6|3-7",
+                "3-8|3-9|3-10|3-11|3-12|3-13|3-14",
+            ],
+        );
     }
 }